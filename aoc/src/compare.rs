@@ -0,0 +1,208 @@
+//! Runs solver plugins next to the built-in solver on the same input and
+//! tabulates their answers and times.
+
+use aoc_plugin::Plugin;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+/// Longer answers, like the grids some days print, are cut short in the table
+const ANSWER_WIDTH: usize = 20;
+
+/// One solver's answers and fastest time, or why it has none
+pub struct Row {
+    pub solver: String,
+    pub outcome: Result<(String, Option<String>), String>,
+    pub micros: Option<u128>,
+}
+
+/// Solves `input` with the built-in solver and each plugin, keeping the
+/// fastest of `repeat` runs. The built-in solver comes first. Solvers that
+/// panic, as they do on malformed input, get a failed row like plugins that do.
+pub fn compare(day: u8, input: &str, plugins: &[Plugin], repeat: usize) -> Vec<Row> {
+    let built_in = time(repeat, || {
+        match panic::catch_unwind(AssertUnwindSafe(|| crate::solve(day, input))) {
+            Ok(Ok((part_one, part_two))) => {
+                Ok((part_one.to_string(), part_two.map(|a| a.to_string())))
+            }
+            Ok(Err(err)) => Err(err.to_string()),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned());
+                Err(match message {
+                    Some(message) => format!("solver panicked: {}", message),
+                    None => "solver panicked".to_string(),
+                })
+            }
+        }
+    });
+    let mut rows = vec![row("built-in", built_in)];
+    for plugin in plugins {
+        let outcome = time(repeat, || {
+            plugin.solve(day, input).map_err(|err| err.to_string())
+        });
+        rows.push(row(plugin.name(), outcome));
+    }
+    rows
+}
+
+fn time<T, E>(repeat: usize, mut solve: impl FnMut() -> Result<T, E>) -> (Result<T, E>, u128) {
+    let mut best = u128::MAX;
+    let mut outcome;
+    let mut runs = repeat.max(1);
+    loop {
+        let now = Instant::now();
+        outcome = solve();
+        best = best.min(now.elapsed().as_micros());
+        runs -= 1;
+        // Failures don't get any faster
+        if runs == 0 || outcome.is_err() {
+            return (outcome, best);
+        }
+    }
+}
+
+fn row(solver: &str, (outcome, micros): (Result<(String, Option<String>), String>, u128)) -> Row {
+    Row {
+        solver: solver.to_string(),
+        micros: outcome.is_ok().then_some(micros),
+        outcome,
+    }
+}
+
+/// Formats the rows as a table, noting the plugins whose answers differ from the built-in solver's
+pub fn report(day: u8, rows: &[Row]) -> String {
+    let expected = rows.first().and_then(|row| row.outcome.as_ref().ok());
+    let width = rows.iter().map(|row| row.solver.len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Day {:02}", day);
+    let _ = writeln!(
+        out,
+        "  {:<width$} {:>w$} {:>w$} {:>10}",
+        "solver",
+        "part one",
+        "part two",
+        "time (μs)",
+        w = ANSWER_WIDTH
+    );
+    for (i, row) in rows.iter().enumerate() {
+        let (part_one, part_two, note) = match &row.outcome {
+            Ok(answers) => {
+                let differs = i > 0 && expected.is_some_and(|expected| expected != answers);
+                (
+                    abbreviate(&answers.0),
+                    abbreviate(answers.1.as_deref().unwrap_or("-")),
+                    if differs { "differs from built-in" } else { "" }.to_string(),
+                )
+            }
+            Err(err) => ("-".to_string(), "-".to_string(), err.clone()),
+        };
+        let micros = row
+            .micros
+            .map_or("-".to_string(), |micros| micros.to_string());
+        let line = format!(
+            "  {:<width$} {:>w$} {:>w$} {:>10}  {}",
+            row.solver,
+            part_one,
+            part_two,
+            micros,
+            note,
+            w = ANSWER_WIDTH
+        );
+        let _ = writeln!(out, "{}", line.trim_end());
+    }
+    out
+}
+
+/// Fits an answer in one column, keeping the end of long ones
+fn abbreviate(answer: &str) -> String {
+    let answer = answer.replace('\n', "⏎");
+    let chars = answer.chars().count();
+    if chars <= ANSWER_WIDTH {
+        return answer;
+    }
+    let tail: String = answer.chars().skip(chars + 1 - ANSWER_WIDTH).collect();
+    format!("…{}", tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(solver: &str, outcome: Result<(&str, Option<&str>), &str>) -> Row {
+        Row {
+            solver: solver.to_string(),
+            micros: outcome.is_ok().then_some(5),
+            outcome: outcome
+                .map(|(one, two)| (one.to_string(), two.map(str::to_string)))
+                .map_err(str::to_string),
+        }
+    }
+
+    #[test]
+    fn notes_differences_and_failures() {
+        let rows = [
+            row("built-in", Ok(("7", Some("5")))),
+            row("same", Ok(("7", Some("5")))),
+            row("wrong", Ok(("7", Some("6")))),
+            row("broken", Err("plugin failed to solve the input")),
+        ];
+        let report = report(1, &rows);
+        let notes: Vec<String> = report
+            .lines()
+            .skip(2)
+            .map(|line| {
+                line.split_whitespace()
+                    .skip(4)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect();
+        assert_eq!(
+            notes,
+            [
+                "",
+                "",
+                "differs from built-in",
+                "plugin failed to solve the input"
+            ]
+        );
+    }
+
+    #[test]
+    fn long_answers_are_abbreviated() {
+        assert_eq!(abbreviate("1234"), "1234");
+        let grid = "#..#\n".repeat(5);
+        let short = abbreviate(&grid);
+        assert_eq!(short.chars().count(), ANSWER_WIDTH);
+        assert!(short.starts_with('…') && short.ends_with("#..#⏎"));
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn built_in_comes_first() {
+        let rows = compare(1, "1\n2\n3\n4", &[], 2);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].solver, "built-in");
+        assert_eq!(
+            rows[0].outcome,
+            Ok(("3".to_string(), Some("1".to_string())))
+        );
+        assert!(rows[0].micros.is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn built_in_panic_is_a_row() {
+        let rows = compare(1, "not a number", &[], 1);
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0]
+            .outcome
+            .as_ref()
+            .is_err_and(|err| err.starts_with("solver panicked")));
+        assert_eq!(rows[0].micros, None);
+    }
+}
@@ -0,0 +1,145 @@
+//! Puzzle parameters for each day, read from `aoc.toml` with one table per day:
+//!
+//! ```toml
+//! [day06]
+//! part_one_days = 18
+//! ```
+//!
+//! Command line overrides like `day06.part_one_days=18` take precedence.
+
+use crate::Params;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{fs, io};
+
+/// Read from the working directory if it exists
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    days: BTreeMap<u8, Params>,
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text.parse().map_err(|err| format!("{}", err))?;
+        let mut config = Config::default();
+        for (name, entries) in table {
+            let day = parse_day(&name)
+                .ok_or_else(|| format!("Expected a [dayXX] table, found {:?}", name))?;
+            let toml::Value::Table(entries) = entries else {
+                return Err(format!("Expected {} to be a table", name));
+            };
+            for (key, value) in entries {
+                let value = value
+                    .as_integer()
+                    .and_then(|value| u64::try_from(value).ok())
+                    .ok_or_else(|| {
+                        format!("Expected {}.{} to be a non-negative integer", name, key)
+                    })?;
+                config.days.entry(day).or_default().set(key, value);
+            }
+        }
+        Ok(config)
+    }
+
+    /// Reads `path`, or `DEFAULT_PATH` if it's None, which may be missing
+    pub fn load(path: Option<&Path>) -> Result<Self, String> {
+        let (path, required) = match path {
+            Some(path) => (path, true),
+            None => (Path::new(DEFAULT_PATH), false),
+        };
+        match fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => Ok(Config::default()),
+            Err(err) => Err(format!("Failed to read {}: {}", path.display(), err)),
+        }
+    }
+
+    /// The parameters set for `day`
+    pub fn params(&self, day: u8) -> Params {
+        self.days.get(&day).cloned().unwrap_or_default()
+    }
+
+    /// Applies an override like `day06.part_one_days=18`. The `dayXX.` prefix
+    /// may be left out when `day` says which day it's for.
+    pub fn set(&mut self, day: Option<u8>, assignment: &str) -> Result<(), String> {
+        let invalid = || format!("Expected [dayXX.]name=value, found {:?}", assignment);
+        let (name, value) = assignment.split_once('=').ok_or_else(invalid)?;
+        let (day, name) = match name.split_once('.') {
+            Some((table, name)) => (parse_day(table).ok_or_else(invalid)?, name),
+            None => (
+                day.ok_or_else(|| format!("Missing dayXX. prefix in {:?}", assignment))?,
+                name,
+            ),
+        };
+        let value = value.trim().parse().map_err(|_| invalid())?;
+        self.days.entry(day).or_default().set(name.trim(), value);
+        Ok(())
+    }
+}
+
+/// The day of a table name like `day06` or `day6`
+fn parse_day(name: &str) -> Option<u8> {
+    name.strip_prefix("day")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_per_day() {
+        let config = Config::parse(
+            "# Shorter runs\n[day06]\npart_one_days = 18\n\n[day14]\npart_two_steps = 1_000\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.params(6).iter().collect::<Vec<_>>(),
+            [("part_one_days", 18)]
+        );
+        assert_eq!(
+            config.params(14).iter().collect::<Vec<_>>(),
+            [("part_two_steps", 1000)]
+        );
+        assert!(config.params(1).is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_config() {
+        assert!(Config::parse("[six]\ndays = 1").is_err());
+        assert!(Config::parse("[day06]\npart_one_days = -1").is_err());
+        assert!(Config::parse("[day06]\npart_one_days = \"18\"").is_err());
+        assert!(Config::parse("day06 = 1").is_err());
+    }
+
+    #[test]
+    fn overrides() {
+        let mut config = Config::parse("[day06]\npart_one_days = 18").unwrap();
+        config.set(None, "day06.part_one_days=20").unwrap();
+        config.set(Some(6), "part_two_days=30").unwrap();
+        let params = config.params(6);
+        let params: Vec<_> = params.iter().collect();
+        assert_eq!(params, [("part_one_days", 20), ("part_two_days", 30)]);
+
+        assert!(config.set(None, "part_one_days=20").is_err());
+        assert!(config.set(Some(6), "part_one_days").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "day06")]
+    fn solvers_read_params() {
+        let mut params = Params::new();
+        params.set("part_one_days", 18);
+        let (part_one, _) = crate::solve_with(6, "3,4,3,1,2", &params, Default::default()).unwrap();
+        assert_eq!(part_one, 26u64.into());
+
+        params.set("days", 18);
+        assert_eq!(
+            crate::solve_with(6, "3,4,3,1,2", &params, Default::default()),
+            Err(crate::Error::Solver(aoc_common::Error::UnknownParam(
+                "days".to_string()
+            )))
+        );
+    }
+}
@@ -0,0 +1,459 @@
+//! Generators for synthetic puzzle inputs of a given size.
+//! They follow the format and the assumptions the solvers make about the real inputs.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+/// Small deterministic xorshift generator, so generated inputs are reproducible
+pub struct Rng(u64);
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed.max(1))
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    /// Uniform in `0..n`
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+    /// Uniform in `lo..=hi`
+    pub fn range(&mut self, lo: usize, hi: usize) -> usize {
+        lo + self.below(hi - lo + 1)
+    }
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generates an input of the given size
+pub type Generate = fn(usize, &mut Rng) -> String;
+
+/// How to generate inputs for a day, and the sizes to try by default
+pub struct Generator {
+    /// What the size counts, e.g. "lines" or "grid side"
+    pub unit: &'static str,
+    pub sizes: &'static [usize],
+    pub generate: Generate,
+}
+
+/// Returns the generator for the given day, or why its input can't be scaled
+pub fn generator(day: u8) -> Result<Generator, &'static str> {
+    let (unit, sizes, generate): (_, &'static [usize], Generate) = match day {
+        1 => (
+            "readings",
+            &[1000, 2000, 4000, 8000, 16000, 32000],
+            sonar_sweep,
+        ),
+        2 => ("commands", &[250, 500, 1000, 2000, 4000], course),
+        3 => ("rows", &[1000, 2000, 4000, 8000, 16000], diagnostic_report),
+        4 => ("boards", &[100, 200, 400, 800, 1600], bingo),
+        5 => ("lines", &[500, 1000, 2000, 4000, 8000], vent_lines),
+        6 => ("fish", &[300, 600, 1200, 2400, 4800], lanternfish),
+        7 => ("crabs", &[1000, 2000, 4000, 8000, 16000], crabs),
+        8 => ("entries", &[200, 400, 800, 1600, 3200], seven_segment),
+        9 => ("rows", &[100, 200, 400, 800, 1600], heightmap),
+        10 => ("lines", &[100, 200, 400, 800, 1600], navigation),
+        11 => return Err("random octopus grids may never all flash at once"),
+        12 => ("small caves", &[2, 3, 4, 5, 6, 7], cave_system),
+        13 => ("dots", &[1000, 2000, 4000, 8000, 16000], transparent_paper),
+        14 => ("template length", &[20, 40, 80, 160, 320], polymer),
+        15 => ("rows", &[50, 100, 200, 400, 800], chiton_cave),
+        16 => (
+            "literal packets",
+            &[500, 1000, 2000, 4000, 8000],
+            transmission,
+        ),
+        17 => ("target distance", &[10, 20, 40, 80, 160], target_area),
+        18 => ("numbers", &[10, 20, 40, 80, 160], snailfish),
+        20 => ("image side", &[10, 20, 40, 80], trench_map),
+        21 => return Err("the input is only the two starting positions"),
+        _ => return Err("there is no generator for this day"),
+    };
+    Ok(Generator {
+        unit,
+        sizes,
+        generate,
+    })
+}
+
+fn join_lines(lines: impl Iterator<Item = String>) -> String {
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+fn sonar_sweep(n: usize, rng: &mut Rng) -> String {
+    let mut depth = 200;
+    join_lines((0..n).map(|_| {
+        depth = (depth + rng.range(0, 20)).saturating_sub(8).max(1);
+        depth.to_string()
+    }))
+}
+
+/// Keeps the depth, and the aim, small enough that neither goes above the surface
+/// and the u32 answers don't overflow
+fn course(n: usize, rng: &mut Rng) -> String {
+    let mut level = 0;
+    join_lines((0..n).map(|_| {
+        let distance = rng.range(1, 9);
+        match rng.below(3) {
+            0 => format!("forward {}", distance),
+            _ if level + distance <= 10 && rng.chance(50) => {
+                level += distance;
+                format!("down {}", distance)
+            }
+            _ if level >= distance => {
+                level -= distance;
+                format!("up {}", distance)
+            }
+            _ => format!("forward {}", distance),
+        }
+    }))
+}
+
+fn diagnostic_report(n: usize, rng: &mut Rng) -> String {
+    join_lines((0..n).map(|_| format!("{:012b}", rng.below(1 << 12))))
+}
+
+/// Every board only uses drawn numbers, so they all win eventually
+fn bingo(n: usize, rng: &mut Rng) -> String {
+    let mut numbers: Vec<usize> = (0..100).collect();
+    rng.shuffle(&mut numbers);
+    let mut input = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    for _ in 0..n {
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..25].chunks(5) {
+            input.push('\n');
+            let row: Vec<String> = row.iter().map(|n| format!("{:>2}", n)).collect();
+            input.push_str(&row.join(" "));
+        }
+    }
+    input
+}
+
+/// Horizontal, vertical and 45 degree lines inside the 1000x1000 grid
+fn vent_lines(n: usize, rng: &mut Rng) -> String {
+    join_lines((0..n).map(|_| {
+        let (x1, y1) = (rng.below(1000), rng.below(1000));
+        let len = rng.range(1, 200) as isize;
+        let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1), (-1, 0), (0, -1)][rng.below(6)];
+        let clamp = |v: isize| v.clamp(0, 999) as usize;
+        // Shorten the line until both ends are on the grid
+        let len = (0..=len)
+            .rev()
+            .find(|&l| {
+                let (x2, y2) = (x1 as isize + dx * l, y1 as isize + dy * l);
+                clamp(x2) as isize == x2 && clamp(y2) as isize == y2
+            })
+            .unwrap();
+        let (x2, y2) = (x1 as isize + dx * len, y1 as isize + dy * len);
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    }))
+}
+
+fn lanternfish(n: usize, rng: &mut Rng) -> String {
+    (0..n)
+        .map(|_| rng.range(1, 5).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn crabs(n: usize, rng: &mut Rng) -> String {
+    (0..n)
+        .map(|_| rng.below(2000).to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Each entry shows all ten digits and four outputs through a random wiring
+fn seven_segment(n: usize, rng: &mut Rng) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    join_lines((0..n).map(|_| {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        rng.shuffle(&mut wiring);
+        let encode = |digit: &str, rng: &mut Rng| {
+            let mut segments: Vec<char> =
+                digit.bytes().map(|s| wiring[(s - b'a') as usize]).collect();
+            rng.shuffle(&mut segments);
+            segments.into_iter().collect::<String>()
+        };
+        let mut signal: Vec<String> = DIGITS.iter().map(|d| encode(d, rng)).collect();
+        let output: Vec<String> = (0..4).map(|_| encode(DIGITS[rng.below(10)], rng)).collect();
+        rng.shuffle(&mut signal);
+        format!("{} | {}", signal.join(" "), output.join(" "))
+    }))
+}
+
+/// Rows as wide as the real input, split into irregular basins walled in by 9s,
+/// each sloping down towards its only low point. Like in the real input, there's
+/// a low point for every 45 or so locations, and basins vary from a couple of
+/// locations to over a hundred.
+fn heightmap(n: usize, rng: &mut Rng) -> String {
+    const WIDTH: usize = 100;
+    const LOCATIONS_PER_BASIN: usize = 45;
+    let locations = n * WIDTH;
+    let neighbours = |i: usize| {
+        let (row, col) = (i / WIDTH, i % WIDTH);
+        [
+            (row > 0).then(|| i - WIDTH),
+            (col > 0).then(|| i - 1),
+            (col + 1 < WIDTH).then(|| i + 1),
+            (row + 1 < n).then(|| i + WIDTH),
+        ]
+        .into_iter()
+        .flatten()
+    };
+    let low_points: Vec<usize> = (0..locations.div_ceil(LOCATIONS_PER_BASIN))
+        .map(|_| rng.below(locations))
+        .collect();
+
+    // Each location joins the basin of the nearest low point, searching out from all of them
+    let mut basin = vec![None; locations];
+    let mut queue = VecDeque::new();
+    for (i, &low_point) in low_points.iter().enumerate() {
+        if basin[low_point].is_none() {
+            basin[low_point] = Some(i);
+            queue.push_back(low_point);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        for next in neighbours(i) {
+            if basin[next].is_none() {
+                basin[next] = basin[i];
+                queue.push_back(next);
+            }
+        }
+    }
+    // Walls off each basin from those to its right and below
+    let wall: Vec<bool> = (0..locations)
+        .map(|i| {
+            let differs = |next: usize| basin[next] != basin[i];
+            (i % WIDTH + 1 < WIDTH && differs(i + 1))
+                || (i + WIDTH < locations && differs(i + WIDTH))
+        })
+        .collect();
+
+    // Heights rise with the distance from the low point inside the walls, so
+    // every other location has a lower neighbour. Cut off locations become 9s.
+    let mut distance = vec![None; locations];
+    for &low_point in &low_points {
+        if !wall[low_point] {
+            distance[low_point] = Some(0);
+            queue.push_back(low_point);
+        }
+    }
+    while let Some(i) = queue.pop_front() {
+        for next in neighbours(i) {
+            if !wall[next] && distance[next].is_none() {
+                distance[next] = distance[i].map(|d| d + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    join_lines(distance.chunks(WIDTH).map(|row| {
+        row.iter()
+            .map(|d| d.map_or('9', |d: usize| char::from(b'0' + d.min(8) as u8)))
+            .collect()
+    }))
+}
+
+/// Rows as wide as the real input of risk levels 1 to 9
+fn chiton_cave(n: usize, rng: &mut Rng) -> String {
+    join_lines((0..n).map(|_| {
+        (0..100)
+            .map(|_| char::from(b'1' + rng.below(9) as u8))
+            .collect()
+    }))
+}
+
+/// Lines of 20 to 40 chunks, a quarter of them corrupted.
+/// The outermost chunk is never closed, like in the real input.
+fn navigation(n: usize, rng: &mut Rng) -> String {
+    const OPEN: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSE: [char; 4] = [')', ']', '}', '>'];
+    join_lines((0..n).map(|_| {
+        let len = rng.range(20, 40);
+        let corrupt_at = rng.chance(25).then(|| rng.range(2, len - 1));
+        let mut stack = vec![rng.below(4)];
+        let mut line = String::from(OPEN[stack[0]]);
+        while line.len() < len {
+            if Some(line.len()) == corrupt_at {
+                let open = stack[stack.len() - 1];
+                line.push(CLOSE[(open + rng.range(1, 3)) % 4]);
+            } else if stack.len() > 1 && rng.chance(40) {
+                line.push(CLOSE[stack.pop().unwrap()]);
+            } else {
+                stack.push(rng.below(4));
+                line.push(OPEN[stack[stack.len() - 1]]);
+            }
+        }
+        line
+    }))
+}
+
+/// A chain of small caves between start and end, each also connected to one of
+/// two big caves. The big caves are never connected to each other.
+fn cave_system(n: usize, rng: &mut Rng) -> String {
+    let small: Vec<String> = (0..n)
+        .map(|i| {
+            format!(
+                "{}{}",
+                char::from(b'a' + i as u8),
+                char::from(b'a' + i as u8)
+            )
+        })
+        .collect();
+    let mut edges = vec![
+        format!("start-{}", small[0]),
+        format!("{}-end", small[n - 1]),
+        format!("start-{}", ["AA", "BB"][rng.below(2)]),
+    ];
+    for (i, cave) in small.iter().enumerate() {
+        if i + 1 < n {
+            edges.push(format!("{}-{}", cave, small[i + 1]));
+        }
+        edges.push(format!("{}-{}", cave, ["AA", "BB"][rng.below(2)]));
+    }
+    edges.push(format!("{}-end", ["AA", "BB"][rng.below(2)]));
+    join_lines(edges.into_iter())
+}
+
+/// Uses the folds of a real input. Dots never land on a fold line, which the
+/// solver assumes.
+fn transparent_paper(n: usize, rng: &mut Rng) -> String {
+    const FOLDS: [(char, usize); 12] = [
+        ('x', 655),
+        ('y', 447),
+        ('x', 327),
+        ('y', 223),
+        ('x', 163),
+        ('y', 111),
+        ('x', 81),
+        ('y', 55),
+        ('x', 40),
+        ('y', 27),
+        ('y', 13),
+        ('y', 6),
+    ];
+    let on_fold_line = |mut x: usize, mut y: usize| {
+        FOLDS.iter().any(|&(axis, line)| {
+            let v = if axis == 'x' { &mut x } else { &mut y };
+            if *v == line {
+                return true;
+            }
+            if *v > line {
+                *v = 2 * line - *v;
+            }
+            false
+        })
+    };
+
+    let mut dots = Vec::with_capacity(n);
+    while dots.len() < n {
+        let (x, y) = (rng.below(2 * 655), rng.below(2 * 447));
+        if !on_fold_line(x, y) {
+            dots.push(format!("{},{}", x, y));
+        }
+    }
+    let folds = FOLDS
+        .iter()
+        .map(|(axis, line)| format!("fold along {}={}", axis, line));
+    format!("{}\n\n{}", dots.join("\n"), join_lines(folds))
+}
+
+/// A template over ten elements, with a rule for every pair
+fn polymer(n: usize, rng: &mut Rng) -> String {
+    const ELEMENTS: &[u8] = b"BCFHKNOPSV";
+    let element = |rng: &mut Rng| char::from(ELEMENTS[rng.below(ELEMENTS.len())]);
+    let mut input: String = (0..n).map(|_| element(rng)).collect();
+    input.push('\n');
+    for &a in ELEMENTS {
+        for &b in ELEMENTS {
+            let _ = write!(input, "\n{}{} -> {}", a as char, b as char, element(rng));
+        }
+    }
+    input
+}
+
+/// Nested sum operators counting their sub-packets, over 4 bit literals
+fn transmission(n: usize, rng: &mut Rng) -> String {
+    fn push_bits(bits: &mut String, value: usize, width: usize) {
+        let _ = write!(bits, "{:0width$b}", value, width = width);
+    }
+    fn packet(bits: &mut String, literals: usize, rng: &mut Rng) {
+        push_bits(bits, rng.below(8), 3);
+        if literals == 1 {
+            push_bits(bits, 4, 3);
+            push_bits(bits, rng.below(16), 5);
+            return;
+        }
+        // Operators hold at most 16 sub-packets, nesting deeper for more
+        let children = literals.min(16);
+        push_bits(bits, 0, 3);
+        push_bits(bits, 1, 1);
+        push_bits(bits, children, 11);
+        for i in 0..children {
+            let share = literals / children + usize::from(i < literals % children);
+            packet(bits, share, rng);
+        }
+    }
+
+    let mut bits = String::new();
+    packet(&mut bits, n.max(1), rng);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    bits.as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).unwrap();
+            format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+        })
+        .collect()
+}
+
+/// A target below and to the right of the launcher, `n` steps away
+fn target_area(n: usize, _: &mut Rng) -> String {
+    format!("target area: x={}..{}, y=-{}..-{}", n, n + n / 2, n, n / 2)
+}
+
+fn snailfish(n: usize, rng: &mut Rng) -> String {
+    fn number(depth: usize, rng: &mut Rng) -> String {
+        if depth > 0 && (depth == 4 || rng.chance(30)) {
+            rng.below(10).to_string()
+        } else {
+            format!("[{},{}]", number(depth + 1, rng), number(depth + 1, rng))
+        }
+    }
+    join_lines((0..n).map(|_| number(0, rng)))
+}
+
+/// Like the real input, lit pixels in the infinite background toggle every step
+fn trench_map(n: usize, rng: &mut Rng) -> String {
+    let pixel = |rng: &mut Rng| if rng.chance(50) { '#' } else { '.' };
+    let mut cipher: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    cipher[0] = '#';
+    cipher[511] = '.';
+    let image = (0..n).map(|_| (0..n).map(|_| pixel(rng)).collect::<String>());
+    format!(
+        "{}\n\n{}",
+        cipher.into_iter().collect::<String>(),
+        join_lines(image)
+    )
+}
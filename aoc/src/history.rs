@@ -0,0 +1,420 @@
+//! Timing history: `aoc run` appends a line per part to a tab-separated file,
+//! which `aoc history` summarises per git commit to show when a day got slower.
+
+use crate::{Answer, Params};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write as _};
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Kept in the working directory unless given with `--history`
+pub const DEFAULT_PATH: &str = "aoc_history.tsv";
+
+const HEADER: &str = "timestamp\tcommit\tmachine\tprofile\tday\tpart\tmicros\tanswer\tparams";
+
+/// Stands for the puzzle's own parameters, which older entries without a
+/// `params` column were all run with
+const DEFAULT_PARAMS: &str = "-";
+
+/// Best times more than this much above the previous commit's are slowdowns
+const SLOWER: f64 = 1.2;
+
+/// Where and how a run happened, shared by all of its entries
+#[derive(Clone, Debug, PartialEq)]
+pub struct Context {
+    /// Short hash of the checked out commit, ending in `+` if the tree has changes
+    pub commit: String,
+    pub machine: String,
+    /// `release` or `debug`, whose timings aren't comparable
+    pub profile: String,
+}
+
+impl Context {
+    pub fn current() -> Self {
+        Context {
+            commit: current_commit(),
+            machine: machine_id(),
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            }
+            .to_string(),
+        }
+    }
+}
+
+/// One part of one run. Solvers compute both parts together, so both parts
+/// of a run share the time of the whole solve.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub context: Context,
+    pub day: u8,
+    pub part: u8,
+    pub micros: u128,
+    /// Stable hash of the answer, to notice when it changes
+    pub answer: u64,
+    /// The puzzle parameters set for the run as `name=value` pairs, whose
+    /// timings are only compared with runs setting the same
+    pub params: String,
+}
+
+impl Entry {
+    pub fn new(
+        context: &Context,
+        params: &Params,
+        day: u8,
+        part: u8,
+        micros: u128,
+        answer: &Answer,
+    ) -> Self {
+        let params: Vec<String> = params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        Entry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            context: context.clone(),
+            day,
+            part,
+            micros,
+            answer: answer_hash(answer),
+            params: if params.is_empty() {
+                DEFAULT_PARAMS.to_string()
+            } else {
+                params.join(",")
+            },
+        }
+    }
+
+    /// Also reads the entries recorded before the parameters were, without them
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields: Vec<&str> = line.split('\t').collect();
+        let params = match fields.len() {
+            9 => fields.pop()?,
+            _ => DEFAULT_PARAMS,
+        };
+        let [timestamp, commit, machine, profile, day, part, micros, answer] = fields[..] else {
+            return None;
+        };
+        Some(Entry {
+            timestamp: timestamp.parse().ok()?,
+            context: Context {
+                commit: commit.to_string(),
+                machine: machine.to_string(),
+                profile: profile.to_string(),
+            },
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            micros: micros.parse().ok()?,
+            answer: u64::from_str_radix(answer, 16).ok()?,
+            params: params.to_string(),
+        })
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:016x}\t{}",
+            self.timestamp,
+            self.context.commit,
+            self.context.machine,
+            self.context.profile,
+            self.day,
+            self.part,
+            self.micros,
+            self.answer,
+            self.params
+        )
+    }
+}
+
+/// Appends `entries` to the history at `path`, creating it with a header
+pub fn record(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if file.metadata()?.len() == 0 {
+        writeln!(file, "{}", HEADER)?;
+    }
+    let lines: String = entries.iter().map(|e| e.to_line() + "\n").collect();
+    file.write_all(lines.as_bytes())
+}
+
+/// Reads the history at `path`, skipping the header, even from older versions
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with("timestamp\t") && !line.is_empty())
+        .map(|(i, line)| {
+            Entry::parse(line)
+                .ok_or_else(|| format!("{}:{}: malformed entry", path.display(), i + 1))
+        })
+        .collect()
+}
+
+/// The best time and answers of one commit, for one part of one day
+struct Revision<'a> {
+    commit: &'a str,
+    runs: usize,
+    best: u128,
+    answers: Vec<u64>,
+}
+
+/// Summarises each part's entries recorded on `context`'s machine and profile,
+/// with one row per commit in the order they were first run. Runs with other
+/// parameters are summarised apart, as their timings aren't comparable.
+/// Marks commits that got slower or changed the answer.
+pub fn report(entries: &[Entry], context: &Context, day: Option<u8>) -> String {
+    let mut parts: Vec<(u8, u8, &str)> = Vec::new();
+    let mut revisions: HashMap<(u8, u8, &str), Vec<Revision>> = HashMap::new();
+    let relevant = entries.iter().filter(|e| {
+        e.context.machine == context.machine
+            && e.context.profile == context.profile
+            && day.is_none_or(|day| e.day == day)
+    });
+    for entry in relevant {
+        let key = (entry.day, entry.part, entry.params.as_str());
+        let commits = revisions.entry(key).or_insert_with(|| {
+            parts.push(key);
+            Vec::new()
+        });
+        match commits
+            .iter_mut()
+            .find(|r| r.commit == entry.context.commit)
+        {
+            Some(revision) => {
+                revision.runs += 1;
+                revision.best = revision.best.min(entry.micros);
+                if !revision.answers.contains(&entry.answer) {
+                    revision.answers.push(entry.answer);
+                }
+            }
+            None => commits.push(Revision {
+                commit: &entry.context.commit,
+                runs: 1,
+                best: entry.micros,
+                answers: vec![entry.answer],
+            }),
+        }
+    }
+    parts.sort();
+
+    let mut out = String::new();
+    if parts.is_empty() {
+        let _ = writeln!(
+            out,
+            "No {} runs recorded on {}",
+            context.profile, context.machine
+        );
+        return out;
+    }
+    for key @ (day, part, params) in parts {
+        let _ = write!(out, "Day {:02} part {}", day, part);
+        if params != DEFAULT_PARAMS {
+            let _ = write!(out, " with {}", params);
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(
+            out,
+            "  {:<10} {:>5} {:>12} {:>8}",
+            "commit", "runs", "best (μs)", "change"
+        );
+        let mut previous: Option<&Revision> = None;
+        for revision in &revisions[&key] {
+            let mut notes = Vec::new();
+            let change = match previous {
+                Some(previous) if previous.best > 0 => {
+                    let ratio = revision.best as f64 / previous.best as f64;
+                    if ratio > SLOWER {
+                        notes.push("slower");
+                    }
+                    format!("{:+.1}%", (ratio - 1.0) * 100.0)
+                }
+                _ => "-".to_string(),
+            };
+            if previous.is_some_and(|p| p.answers.last() != revision.answers.first()) {
+                notes.push("answer changed");
+            }
+            if revision.answers.len() > 1 {
+                notes.push("answers differ between runs");
+            }
+            let row = format!(
+                "  {:<10} {:>5} {:>12} {:>8}  {}",
+                revision.commit,
+                revision.runs,
+                revision.best,
+                change,
+                notes.join(", ")
+            );
+            let _ = writeln!(out, "{}", row.trim_end());
+            previous = Some(revision);
+        }
+    }
+    out
+}
+
+/// FNV-1a of the answer as printed, which unlike `DefaultHasher` is stable across builds
+fn answer_hash(answer: &Answer) -> u64 {
+    answer
+        .to_string()
+        .bytes()
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        })
+}
+
+fn current_commit() -> String {
+    let git = |args: &[&str]| Command::new("git").args(args).output().ok();
+    let Some(output) = git(&["rev-parse", "--short", "HEAD"]).filter(|o| o.status.success()) else {
+        return "unknown".to_string();
+    };
+    let mut commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|output| !output.stdout.is_empty());
+    if dirty {
+        commit.push('+');
+    }
+    commit
+}
+
+/// The systemd machine id, falling back to the host name
+fn machine_id() -> String {
+    let read = |path| fs::read_to_string(path).ok();
+    read("/etc/machine-id")
+        .map(|id| id.trim().chars().take(12).collect())
+        .or_else(|| read("/etc/hostname").map(|name| name.trim().to_string()))
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .filter(|id: &String| !id.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(commit: &str) -> Context {
+        Context {
+            commit: commit.to_string(),
+            machine: "m".to_string(),
+            profile: "release".to_string(),
+        }
+    }
+
+    fn entry(commit: &str, part: u8, micros: u128, answer: u32) -> Entry {
+        Entry::new(
+            &context(commit),
+            &Params::new(),
+            9,
+            part,
+            micros,
+            &answer.into(),
+        )
+    }
+
+    #[test]
+    fn lines_round_trip() {
+        let entry = entry("abc1234+", 2, 7000, 15);
+        assert_eq!(Entry::parse(&entry.to_line()), Some(entry));
+        assert_eq!(Entry::parse("1\tabc\tm\trelease\t9\t1\t7000"), None);
+
+        let mut params = Params::new();
+        params.set("part_one_days", 18);
+        params.set("part_two_days", 80);
+        let entry = Entry::new(&context("a"), &params, 6, 1, 10, &26u32.into());
+        assert_eq!(entry.params, "part_one_days=18,part_two_days=80");
+        assert_eq!(Entry::parse(&entry.to_line()), Some(entry));
+    }
+
+    #[test]
+    fn loads_entries_without_params() {
+        let path = std::env::temp_dir().join(format!("aoc-history-old-{}.tsv", std::process::id()));
+        let old = "timestamp\tcommit\tmachine\tprofile\tday\tpart\tmicros\tanswer\n\
+                   1\ta\tm\trelease\t9\t1\t10\t0000000000000001\n";
+        fs::write(&path, old).unwrap();
+        let entries = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].params, DEFAULT_PARAMS);
+    }
+
+    #[test]
+    fn records_and_loads() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.tsv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let entries = [entry("a", 1, 10, 1), entry("a", 2, 10, 2)];
+        record(&path, &entries[..1]).unwrap();
+        record(&path, &entries[1..]).unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        assert_eq!(text.lines().next(), Some(HEADER));
+        assert_eq!(load(&path), Ok(entries.to_vec()));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn marks_slowdowns_and_changed_answers() {
+        let entries = [
+            entry("a", 1, 7000, 15),
+            entry("a", 1, 6000, 15),
+            entry("b", 1, 6500, 15),
+            entry("c", 1, 9000, 15),
+            entry("d", 1, 9000, 16),
+        ];
+        let report = report(&entries, &context("d"), Some(9));
+        let rows: Vec<Vec<&str>> = report
+            .lines()
+            .skip(2)
+            .map(|row| row.split_whitespace().collect())
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                vec!["a", "2", "6000", "-"],
+                vec!["b", "1", "6500", "+8.3%"],
+                vec!["c", "1", "9000", "+38.5%", "slower"],
+                vec!["d", "1", "9000", "+0.0%", "answer", "changed"],
+            ]
+        );
+    }
+
+    #[test]
+    fn other_params_are_summarised_apart() {
+        let mut params = Params::new();
+        params.set("part_one_days", 18);
+        let entries = [
+            entry("a", 1, 7000, 15),
+            Entry::new(&context("a"), &params, 9, 1, 10, &26u32.into()),
+            entry("b", 1, 7000, 15),
+        ];
+        let report = report(&entries, &context("b"), None);
+        let titles: Vec<&str> = report.lines().filter(|l| l.starts_with("Day")).collect();
+        assert_eq!(
+            titles,
+            ["Day 09 part 1", "Day 09 part 1 with part_one_days=18"]
+        );
+        assert!(!report.contains("answer changed"));
+    }
+
+    #[test]
+    fn other_machines_are_left_out() {
+        let mut other = entry("a", 1, 10, 1);
+        other.context.machine = "n".to_string();
+        assert_eq!(
+            report(&[other], &context("a"), None),
+            "No release runs recorded on m\n"
+        );
+    }
+
+    #[test]
+    fn answer_hash_is_stable() {
+        assert_eq!(answer_hash(&"".to_string().into()), 0xcbf29ce484222325);
+        assert_eq!(answer_hash(&"a".to_string().into()), 0xaf63dc4c8601ec8c);
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+
+pub mod compare;
+pub mod config;
+pub mod generate;
+pub mod history;
+pub mod minimize;
+pub mod scale;
+
+pub use aoc_common::budget::Budget;
+pub use aoc_common::{Answer, Answers, Params, Violation};
+
+type Solver = fn(&str) -> Result<Answers, aoc_common::Error>;
+type Configured = fn(&str, &Params) -> Result<Answers, aoc_common::Error>;
+type Checker = fn(&str, &Params) -> Result<Vec<Violation>, aoc_common::Error>;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    UnknownDay(u8),
+    /// The day has a solver, but its cargo feature was not enabled
+    NotCompiled(u8),
+    Solver(aoc_common::Error),
+}
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::UnknownDay(day) => write!(f, "no solver for day {}", day),
+            Error::NotCompiled(day) => write!(
+                f,
+                "day {} was not compiled in, rebuild with the `day{:02}` feature",
+                day, day
+            ),
+            Error::Solver(err) => write!(f, "{}", err),
+        }
+    }
+}
+impl std::error::Error for Error {}
+
+impl From<aoc_common::Error> for Error {
+    fn from(err: aoc_common::Error) -> Self {
+        Error::Solver(err)
+    }
+}
+
+/// Every day with a solver in the repository, whether or not its feature is enabled
+const SOLVED_DAYS: [u8; 20] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 20, 21,
+];
+
+fn solver(day: u8) -> Result<Solver, Error> {
+    match day {
+        #[cfg(feature = "day01")]
+        1 => Ok(day_01::solve),
+        #[cfg(feature = "day02")]
+        2 => Ok(day_02::solve),
+        #[cfg(feature = "day03")]
+        3 => Ok(day_03::solve),
+        #[cfg(feature = "day04")]
+        4 => Ok(day_04::solve),
+        #[cfg(feature = "day05")]
+        5 => Ok(day_05::solve),
+        #[cfg(feature = "day06")]
+        6 => Ok(day_06::solve),
+        #[cfg(feature = "day07")]
+        7 => Ok(day_07::solve),
+        #[cfg(feature = "day08")]
+        8 => Ok(day_08::solve),
+        #[cfg(feature = "day09")]
+        9 => Ok(day_09::solve),
+        #[cfg(feature = "day10")]
+        10 => Ok(day_10::solve),
+        #[cfg(feature = "day11")]
+        11 => Ok(day_11::solve),
+        #[cfg(feature = "day12")]
+        12 => Ok(day_12::solve),
+        #[cfg(feature = "day13")]
+        13 => Ok(day_13::solve),
+        #[cfg(feature = "day14")]
+        14 => Ok(day_14::solve),
+        #[cfg(feature = "day15")]
+        15 => Ok(day_15::solve),
+        #[cfg(feature = "day16")]
+        16 => Ok(day_16::solve),
+        #[cfg(feature = "day17")]
+        17 => Ok(day_17::solve),
+        #[cfg(feature = "day18")]
+        18 => Ok(day_18::solve),
+        #[cfg(feature = "day20")]
+        20 => Ok(day_20::solve),
+        #[cfg(feature = "day21")]
+        21 => Ok(day_21::solve),
+        // Only reachable for days whose feature is disabled
+        _ if SOLVED_DAYS.contains(&day) => Err(Error::NotCompiled(day)),
+        _ => Err(Error::UnknownDay(day)),
+    }
+}
+
+/// The solvers of the days with puzzle parameters, reading them from `Params`
+fn configured(day: u8) -> Result<Option<Configured>, Error> {
+    solver(day)?;
+    Ok(match day {
+        #[cfg(feature = "day03")]
+        3 => Some(|input, params| day_03::solve_with(input, &day_03::Config::from_params(params)?)),
+        #[cfg(feature = "day06")]
+        6 => Some(|input, params| day_06::solve_with(input, &day_06::Config::from_params(params)?)),
+        #[cfg(feature = "day11")]
+        11 => {
+            Some(|input, params| day_11::solve_with(input, &day_11::Config::from_params(params)?))
+        }
+        #[cfg(feature = "day14")]
+        14 => {
+            Some(|input, params| day_14::solve_with(input, &day_14::Config::from_params(params)?))
+        }
+        #[cfg(feature = "day15")]
+        15 => {
+            Some(|input, params| day_15::solve_with(input, &day_15::Config::from_params(params)?))
+        }
+        #[cfg(feature = "day20")]
+        20 => {
+            Some(|input, params| day_20::solve_with(input, &day_20::Config::from_params(params)?))
+        }
+        #[cfg(feature = "day21")]
+        21 => {
+            Some(|input, params| day_21::solve_with(input, &day_21::Config::from_params(params)?))
+        }
+        _ => None,
+    })
+}
+
+/// The input checks of the days that have them.
+/// Only days 3 and 15 check against their parameters, the others ignore them.
+fn checker(day: u8) -> Result<Option<Checker>, Error> {
+    solver(day)?;
+    Ok(match day {
+        #[cfg(feature = "day03")]
+        3 => Some(|input, params| {
+            Ok(day_03::check_with(
+                input,
+                &day_03::Config::from_params(params)?,
+            ))
+        }),
+        #[cfg(feature = "day04")]
+        4 => Some(|input, _| Ok(day_04::check(input))),
+        #[cfg(feature = "day05")]
+        5 => Some(|input, _| Ok(day_05::check(input))),
+        #[cfg(feature = "day11")]
+        11 => Some(|input, _| Ok(day_11::check(input))),
+        #[cfg(feature = "day12")]
+        12 => Some(|input, _| Ok(day_12::check(input))),
+        #[cfg(feature = "day15")]
+        15 => Some(|input, params| {
+            Ok(day_15::check_with(
+                input,
+                &day_15::Config::from_params(params)?,
+            ))
+        }),
+        _ => None,
+    })
+}
+
+/// The days compiled into this build, in order
+pub fn days() -> impl Iterator<Item = u8> {
+    (1..=25).filter(|&day| solver(day).is_ok())
+}
+
+/// Checks that the given day has a solver compiled into this build
+pub fn available(day: u8) -> Result<(), Error> {
+    solver(day).map(|_| ())
+}
+
+/// Solves both parts of the given day for the given puzzle input
+pub fn solve(day: u8, input: &str) -> Result<Answers, Error> {
+    let solver = solver(day)?;
+
+    Ok(solver(input)?)
+}
+
+/// Checks the input against the assumptions of the day's solver without solving it,
+/// returning every violation, or None if the day has no checks
+pub fn check(day: u8, input: &str) -> Result<Option<Vec<Violation>>, Error> {
+    check_with(day, input, &Params::new())
+}
+
+/// Like `check`, with the puzzle parameters in `params` overriding the defaults
+pub fn check_with(day: u8, input: &str, params: &Params) -> Result<Option<Vec<Violation>>, Error> {
+    match checker(day)? {
+        Some(check) => Ok(Some(check(input, params)?)),
+        None => Ok(None),
+    }
+}
+
+/// Like `solve`, failing with `BudgetExceeded` if the solver runs past `budget`
+pub fn solve_within(day: u8, input: &str, budget: Budget) -> Result<Answers, Error> {
+    solve_with(day, input, &Params::new(), budget)
+}
+
+/// Like `solve_within`, with the puzzle parameters in `params` overriding the defaults.
+/// Fails with `UnknownParam` on parameters the day doesn't have.
+pub fn solve_with(day: u8, input: &str, params: &Params, budget: Budget) -> Result<Answers, Error> {
+    let solver = solver(day)?;
+    let configured = configured(day)?;
+
+    Ok(aoc_common::budget::limit(budget, || match configured {
+        Some(solve) => solve(input, params),
+        None => params.only(&[]).and_then(|()| solver(input)),
+    })?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "all")]
+    fn days_skip_unsolved() {
+        assert_eq!(days().count(), 20);
+        assert!(!days().any(|day| day == 19));
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn solve_example() {
+        let input = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+        assert_eq!(solve(1, input), Ok((7u32.into(), Some(5u32.into()))));
+    }
+
+    #[test]
+    #[cfg(feature = "day11")]
+    fn budget_stops_solver() {
+        let input = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n\
+                     4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+        let budget = Budget {
+            iterations: Some(100),
+            ..Budget::default()
+        };
+        // The example only synchronises at step 195
+        match solve_within(11, input, budget) {
+            Err(Error::Solver(aoc_common::Error::BudgetExceeded(progress))) => {
+                assert_eq!(progress.iterations, 101)
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "day12")]
+    fn check_reports_violations() {
+        let violations = check(12, "start-A\nA-B\nA-").unwrap().unwrap();
+        let messages: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            messages,
+            [
+                "2: big caves A and B are connected",
+                "3:3: \"\" is not a cave name",
+                "3: there is no end cave",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_day() {
+        assert_eq!(solve(19, ""), Err(Error::UnknownDay(19)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "day02"))]
+    fn not_compiled() {
+        assert_eq!(solve(2, ""), Err(Error::NotCompiled(2)));
+    }
+}
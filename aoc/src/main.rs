@@ -0,0 +1,406 @@
+use aoc::config::Config;
+use aoc::history::{self, Context, Entry};
+use aoc::minimize::Failure;
+use aoc::{Answer, Answers, Budget, Params};
+use aoc_plugin::Plugin;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage:
+  aoc run [day] [input] [--time-limit ms] [--max-iterations n]
+          [--config file] [--set [dayXX.]name=value].. [--history file | --no-history]
+      Solves the given day, or every day, printing both answers.
+      The input defaults to day_XX/input.txt. Solvers running past
+      the limits stop with an error saying how far they got.
+      Puzzle parameters are read from aoc.toml, or the --config file,
+      and --set overrides them. Runs of the default input are timed
+      into aoc_history.tsv along with the parameters set.
+  aoc history [day] [--history file] [--machine id] [--profile debug|release]
+      Shows the best time of each part per git commit, marking the
+      commits that got slower or changed the answer. Only compares
+      runs from this machine and build profile by default, and runs
+      with the same parameters.
+  aoc check <day> [input] [--config file] [--set name=value]..
+      Checks the input against the assumptions of the day's solver
+      without solving it, listing every violation.
+  aoc scale <day> [--sizes a,b,..] [--repeat n] [--seed n]
+      Times the day on generated inputs of increasing size and fits
+      the growth exponent. Build with --release for useful timings.
+  aoc minimize <day> <input> (--panic | --any-panic | --reference cmd | --timeout ms)
+          [--output file]
+      Shrinks a failing input to a minimal one that still panics with
+      the same message, or with any message, gives answers different
+      from a reference command reading the input on stdin, or runs past
+      the timeout. Prints the result unless --output is given.
+  aoc compare <day> [input] --plugin path [--plugin path].. [--repeat n]
+      Solves the input with the built-in solver and each solver plugin,
+      showing their answers and fastest times side by side.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("check") => check(&args[1..]),
+        Some("scale") => scale(&args[1..]),
+        Some("minimize") => minimize(&args[1..]),
+        Some("history") => show_history(&args[1..]),
+        Some("compare") => compare(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut budget = Budget::default();
+    let mut config_path = None;
+    let mut overrides = Vec::new();
+    let mut history_path = Some(history::DEFAULT_PATH.to_string());
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            positional.push(arg);
+            continue;
+        }
+        if arg == "--no-history" {
+            history_path = None;
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        let invalid = || format!("Invalid value {:?} for {}", value, arg);
+        match arg.as_str() {
+            "--history" => history_path = Some(value.clone()),
+            "--time-limit" => {
+                let millis = value.parse().map_err(|_| invalid())?;
+                budget.time = Some(Duration::from_millis(millis));
+            }
+            "--max-iterations" => budget.iterations = Some(value.parse().map_err(|_| invalid())?),
+            "--config" => config_path = Some(value),
+            "--set" => overrides.push(value),
+            _ => return Err(format!("Unknown option {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let (days, input) = match positional[..] {
+        [] => (aoc::days().collect(), None),
+        [day] => (vec![parse_day(day)?], None),
+        [day, input] => (vec![parse_day(day)?], Some(input)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let single_day = (days.len() == 1).then(|| days[0]);
+    let config = load_config(config_path, &overrides, single_day)?;
+    // Timings of other inputs aren't comparable with the history, while the
+    // parameters are recorded so runs with the same ones can be compared
+    let context = history_path
+        .as_ref()
+        .filter(|_| input.is_none())
+        .map(|_| Context::current());
+
+    days.into_iter().try_for_each(|day| {
+        let input = input.cloned().unwrap_or_else(|| default_input(day));
+        let params = config.params(day);
+        let (micros, answers) = run_day(day, &input, budget, &params)?;
+
+        if let (Some(path), Some(context)) = (&history_path, &context) {
+            let parts = [Some(answers.0), answers.1];
+            let entries: Vec<Entry> = (1..)
+                .zip(parts.iter())
+                .filter_map(|(part, answer)| {
+                    Some(Entry::new(
+                        context,
+                        &params,
+                        day,
+                        part,
+                        micros,
+                        answer.as_ref()?,
+                    ))
+                })
+                .collect();
+            if let Err(err) = history::record(Path::new(path), &entries) {
+                eprintln!("Failed to record the timing in {}: {}", path, err);
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Prints the answers and returns how long solving took in μs
+fn run_day(
+    day: u8,
+    input_path: &str,
+    budget: Budget,
+    params: &Params,
+) -> Result<(u128, Answers), String> {
+    aoc::available(day).map_err(|err| err.to_string())?;
+    let contents = fs::read_to_string(input_path)
+        .map_err(|err| format!("Failed to read {}: {}", input_path, err))?;
+
+    let now = Instant::now();
+    let answers = aoc::solve_with(day, &contents, params, budget)
+        .map_err(|err| format!("Day {:02}: {}", day, err))?;
+    let time = now.elapsed().as_micros();
+
+    println!("Day {:02}", day);
+    print_answer("Part one", &answers.0);
+    if let Some(part_two) = &answers.1 {
+        print_answer("Part two", part_two);
+    }
+    println!("Time: {} μs\n", time);
+    Ok((time, answers))
+}
+
+/// Multi-line answers like grids start on their own line
+fn print_answer(label: &str, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("{}:\n{}", label, answer);
+    } else {
+        println!("{}: {}", label, answer);
+    }
+}
+
+fn check(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut config_path = None;
+    let mut overrides = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            positional.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        match arg.as_str() {
+            "--config" => config_path = Some(value),
+            "--set" => overrides.push(value),
+            _ => return Err(format!("Unknown option {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let (day, input_path) = match positional[..] {
+        [day] => {
+            let day = parse_day(day)?;
+            (day, default_input(day))
+        }
+        [day, input] => (parse_day(day)?, input.clone()),
+        _ => return Err(USAGE.to_string()),
+    };
+    let config = load_config(config_path, &overrides, Some(day))?;
+    aoc::available(day).map_err(|err| err.to_string())?;
+    let contents = fs::read_to_string(&input_path)
+        .map_err(|err| format!("Failed to read {}: {}", input_path, err))?;
+
+    match aoc::check_with(day, &contents, &config.params(day))
+        .map_err(|err| format!("Day {:02}: {}", day, err))?
+    {
+        None => Err(format!("Day {:02} has no input checks", day)),
+        Some(violations) if violations.is_empty() => {
+            println!("{}: ok", input_path);
+            Ok(())
+        }
+        Some(violations) => {
+            for violation in &violations {
+                println!("{}:{}", input_path, violation);
+            }
+            Err(format!("{} violations found", violations.len()))
+        }
+    }
+}
+
+fn scale(args: &[String]) -> Result<(), String> {
+    let (day, options) = args.split_first().ok_or(USAGE)?;
+    let day = parse_day(day)?;
+    let mut sizes = Vec::new();
+    let mut repeat = 3;
+    let mut seed = 2021;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let value = options
+            .next()
+            .ok_or_else(|| format!("Missing value for {}\n\n{}", option, USAGE))?;
+        let invalid = || format!("Invalid value {:?} for {}", value, option);
+        match option.as_str() {
+            "--sizes" => {
+                sizes = value
+                    .split(',')
+                    .map(|size| size.parse().map_err(|_| invalid()))
+                    .collect::<Result<_, _>>()?
+            }
+            "--repeat" => repeat = value.parse().map_err(|_| invalid())?,
+            "--seed" => seed = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown option {}\n\n{}", option, USAGE)),
+        }
+    }
+
+    let scaling = aoc::scale::measure(day, &sizes, repeat, seed)?;
+    print!("{}", aoc::scale::report(&scaling));
+    Ok(())
+}
+
+fn minimize(args: &[String]) -> Result<(), String> {
+    let [day, input_path, options @ ..] = args else {
+        return Err(USAGE.to_string());
+    };
+    let day = parse_day(day)?;
+    let mut failure = None;
+    let mut output = None;
+
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--panic" => failure = Some(Failure::Panic { any: false }),
+            "--any-panic" => failure = Some(Failure::Panic { any: true }),
+            _ => {
+                let value = options
+                    .next()
+                    .ok_or_else(|| format!("Missing value for {}\n\n{}", option, USAGE))?;
+                let invalid = || format!("Invalid value {:?} for {}", value, option);
+                match option.as_str() {
+                    "--reference" => {
+                        failure = Some(Failure::Mismatch {
+                            reference: value.clone(),
+                        })
+                    }
+                    "--timeout" => {
+                        failure = Some(Failure::Timeout {
+                            limit: Duration::from_millis(value.parse().map_err(|_| invalid())?),
+                            runner: env::current_exe().map_err(|err| err.to_string())?,
+                        })
+                    }
+                    "--output" => output = Some(value),
+                    _ => return Err(format!("Unknown option {}\n\n{}", option, USAGE)),
+                }
+            }
+        }
+    }
+    let failure = failure.ok_or_else(|| format!("Missing failure predicate\n\n{}", USAGE))?;
+
+    let contents = fs::read_to_string(input_path)
+        .map_err(|err| format!("Failed to read {}: {}", input_path, err))?;
+    let minimized = aoc::minimize::minimize(day, &contents, &failure)?;
+    eprintln!(
+        "Reduced {} to {} bytes in {} attempts",
+        contents.len(),
+        minimized.input.len(),
+        minimized.attempts
+    );
+    match output {
+        Some(path) => fs::write(path, &minimized.input)
+            .map_err(|err| format!("Failed to write {}: {}", path, err)),
+        None => {
+            print!("{}", minimized.input);
+            Ok(())
+        }
+    }
+}
+
+fn show_history(args: &[String]) -> Result<(), String> {
+    let mut day = None;
+    let mut path = history::DEFAULT_PATH.to_string();
+    let mut context = Context::current();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            match day {
+                None => day = Some(parse_day(arg)?),
+                Some(_) => return Err(USAGE.to_string()),
+            }
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        match arg.as_str() {
+            "--history" => path = value.clone(),
+            "--machine" => context.machine = value.clone(),
+            "--profile" => context.profile = value.clone(),
+            _ => return Err(format!("Unknown option {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let entries = history::load(Path::new(&path))?;
+    print!("{}", history::report(&entries, &context, day));
+    Ok(())
+}
+
+fn compare(args: &[String]) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut plugins = Vec::new();
+    let mut repeat = 3;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            positional.push(arg);
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}\n\n{}", arg, USAGE))?;
+        let invalid = || format!("Invalid value {:?} for {}", value, arg);
+        match arg.as_str() {
+            "--plugin" => {
+                plugins.push(Plugin::load(value).map_err(|err| format!("{}: {}", value, err))?)
+            }
+            "--repeat" => repeat = value.parse().map_err(|_| invalid())?,
+            _ => return Err(format!("Unknown option {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let (day, input_path) = match positional[..] {
+        [day] => {
+            let day = parse_day(day)?;
+            (day, default_input(day))
+        }
+        [day, input] => (parse_day(day)?, input.clone()),
+        _ => return Err(USAGE.to_string()),
+    };
+    if plugins.is_empty() {
+        return Err(format!("Missing --plugin\n\n{}", USAGE));
+    }
+    aoc::available(day).map_err(|err| err.to_string())?;
+    let contents = fs::read_to_string(&input_path)
+        .map_err(|err| format!("Failed to read {}: {}", input_path, err))?;
+
+    let rows = aoc::compare::compare(day, &contents, &plugins, repeat);
+    print!("{}", aoc::compare::report(day, &rows));
+    Ok(())
+}
+
+/// Reads the config file and applies the `--set` overrides, which may leave
+/// out the day when only `day` is being run
+fn load_config(
+    path: Option<&String>,
+    overrides: &[&String],
+    day: Option<u8>,
+) -> Result<Config, String> {
+    let mut config = Config::load(path.map(Path::new))?;
+    for assignment in overrides {
+        config.set(day, assignment)?;
+    }
+    Ok(config)
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    day.parse()
+        .map_err(|_| format!("Invalid day {:?}\n\n{}", day, USAGE))
+}
+
+fn default_input(day: u8) -> String {
+    format!("day_{:02}/input.txt", day)
+}
@@ -0,0 +1,570 @@
+//! Delta debugging of failing inputs: repeatedly removes parts of an input
+//! that still makes the solver fail, until no single part can be removed.
+//! Inputs are split along each day's structure, so the reduced input keeps
+//! the shape the solver expects.
+
+use aoc_common::bits::{BitReader, BitVec, BitWriter};
+use std::cell::Cell;
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+use std::{fs, process, thread};
+
+thread_local! {
+    /// Set while this thread is minimising, where panics are expected
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Puts back whether this thread was quiet before `quietly`, even if `f` panics
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        QUIET.with(|quiet| quiet.set(self.0));
+    }
+}
+
+/// Wraps the panic hook, once, so that panics are only reported outside `quietly`
+fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                hook(info);
+            }
+        }));
+    });
+    let _restore = Restore(QUIET.with(|quiet| quiet.replace(true)));
+    f()
+}
+
+/// How an input counts as failing
+pub enum Failure {
+    /// The solver panics. Unless `any` is set, the panic message must match the
+    /// original one, so the input doesn't drift into an unrelated bug.
+    Panic { any: bool },
+    /// The solver's answers differ from those of a shell command, which reads
+    /// the input on stdin and prints one answer per line
+    Mismatch { reference: String },
+    /// Solving takes longer than `limit`. Each attempt runs `runner run <day>`
+    /// in a child process so that it can be killed.
+    Timeout { limit: Duration, runner: PathBuf },
+}
+
+pub struct Minimized {
+    pub input: String,
+    /// Number of candidate inputs tried
+    pub attempts: usize,
+}
+
+/// Reduces `input` to a minimal one that still fails the same way
+pub fn minimize(day: u8, input: &str, failure: &Failure) -> Result<Minimized, String> {
+    crate::available(day).map_err(|err| err.to_string())?;
+    let input = input.replace("\r\n", "\n");
+
+    // Panics are expected from here on and would flood stderr
+    quietly(|| {
+        let mut fails: Box<dyn FnMut(&str) -> bool> = match failure {
+            Failure::Panic { any } => {
+                let original = panic_message(day, &input)
+                    .ok_or_else(|| format!("Day {} doesn't panic on this input", day))?;
+                let any = *any;
+                Box::new(move |candidate| {
+                    panic_message(day, candidate).is_some_and(|message| any || message == original)
+                })
+            }
+            Failure::Mismatch { reference } => {
+                Box::new(move |candidate| mismatch(day, candidate, reference))
+            }
+            Failure::Timeout { limit, runner } => {
+                Box::new(move |candidate| times_out(day, candidate, *limit, runner))
+            }
+        };
+        if !fails(&input) {
+            return Err(format!("Day {} doesn't fail on this input", day));
+        }
+        Ok(minimize_with(day, &input, &mut fails))
+    })
+}
+
+/// Reduces `input` with an arbitrary predicate, which must hold for `input` itself
+pub fn minimize_with(day: u8, input: &str, fails: &mut dyn FnMut(&str) -> bool) -> Minimized {
+    let mut attempts = 0;
+    let mut counted = |candidate: &str| {
+        attempts += 1;
+        fails(candidate)
+    };
+    let input = if day == 16 {
+        minimize_packets(input, &mut counted)
+    } else {
+        minimize_sections(Document::parse(day, input), &mut counted)
+    };
+    Minimized { input, attempts }
+}
+
+/// Zeller's ddmin: returns a subsequence of `units` for which `fails` still
+/// holds, from which no single unit can be removed
+fn ddmin<T: Clone>(mut units: Vec<T>, fails: &mut dyn FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+    while units.len() >= 2 {
+        let size = units.len().div_ceil(chunks);
+        let starts = (0..units.len()).step_by(size);
+
+        let subset = starts.clone().find_map(|start| {
+            let subset = &units[start..(start + size).min(units.len())];
+            fails(subset).then(|| subset.to_vec())
+        });
+        if let Some(subset) = subset {
+            units = subset;
+            chunks = 2;
+            continue;
+        }
+
+        let complement = starts.clone().find_map(|start| {
+            let mut complement = units[..start].to_vec();
+            complement.extend_from_slice(&units[(start + size).min(units.len())..]);
+            fails(&complement).then_some(complement)
+        });
+        if let Some(complement) = complement {
+            units = complement;
+            chunks = (chunks - 1).max(2);
+            continue;
+        }
+
+        if chunks >= units.len() {
+            break;
+        }
+        chunks = (chunks * 2).min(units.len());
+    }
+    if units.len() == 1 && fails(&[]) {
+        units.clear();
+    }
+    units
+}
+
+/// One part of an input, made of units that can be removed independently
+struct Section {
+    units: Vec<String>,
+    separator: &'static str,
+    /// Fixed sections, like day 20's enhancement algorithm, are never reduced
+    fixed: bool,
+}
+impl Section {
+    fn new(text: &str, separator: &'static str) -> Self {
+        let units = if separator.is_empty() {
+            text.chars().map(String::from).collect()
+        } else {
+            text.split(separator)
+                .filter(|unit| !unit.trim().is_empty())
+                .map(|unit| unit.trim_matches('\n').to_string())
+                .collect()
+        };
+        Section {
+            units,
+            separator,
+            fixed: false,
+        }
+    }
+
+    fn fixed(text: &str) -> Self {
+        Section {
+            units: vec![text.to_string()],
+            separator: "",
+            fixed: true,
+        }
+    }
+}
+
+/// An input split into sections separated by blank lines
+struct Document {
+    sections: Vec<Section>,
+}
+impl Document {
+    fn parse(day: u8, input: &str) -> Self {
+        let input = input.trim_end();
+        let (head, rest) = input.split_once("\n\n").unwrap_or((input, ""));
+        let sections = match day {
+            // Drawn numbers, then 5-line boards
+            4 => vec![Section::new(head, ","), Section::new(rest, "\n\n")],
+            // Comma-separated numbers on a single line
+            6 | 7 => vec![Section::new(input, ",")],
+            // Dots, then fold instructions
+            13 => vec![Section::new(head, "\n"), Section::new(rest, "\n")],
+            // The polymer template, then insertion rules
+            14 => vec![Section::new(head, ""), Section::new(rest, "\n")],
+            // The enhancement algorithm, then rows of the image
+            20 => vec![Section::fixed(head), Section::new(rest, "\n")],
+            _ => vec![Section::new(input, "\n")],
+        };
+        Document { sections }
+    }
+
+    fn render(&self) -> String {
+        let sections: Vec<String> = self
+            .sections
+            .iter()
+            .map(|section| section.units.join(section.separator))
+            .collect();
+        sections.join("\n\n") + "\n"
+    }
+}
+
+/// Reduces each section in turn with the others held fixed, until none shrinks
+fn minimize_sections(mut document: Document, fails: &mut dyn FnMut(&str) -> bool) -> String {
+    loop {
+        let mut shrunk = false;
+        for i in 0..document.sections.len() {
+            if document.sections[i].fixed {
+                continue;
+            }
+            let units = document.sections[i].units.clone();
+            let before = units.len();
+            let units = ddmin(units, &mut |candidate| {
+                let kept = std::mem::replace(&mut document.sections[i].units, candidate.to_vec());
+                let failed = fails(&document.render());
+                document.sections[i].units = kept;
+                failed
+            });
+            shrunk |= units.len() < before;
+            document.sections[i].units = units;
+        }
+        if !shrunk {
+            return document.render();
+        }
+    }
+}
+
+/// A day 16 packet. Operators are re-encoded with a sub-packet count, so
+/// that removing sub-packets keeps the transmission well-formed.
+#[derive(Clone, Debug, PartialEq)]
+enum Packet {
+    Literal {
+        version: u64,
+        value: u64,
+    },
+    Operator {
+        version: u64,
+        kind: u64,
+        children: Vec<Packet>,
+    },
+}
+impl Packet {
+    fn decode(reader: &mut BitReader) -> Option<Packet> {
+        let version = reader.read(3)?;
+        let kind = reader.read(3)?;
+        if kind == 4 {
+            let mut value = 0;
+            loop {
+                let group = reader.read(5)?;
+                value = value << 4 | group & 0xf;
+                if group & 0x10 == 0 {
+                    return Some(Packet::Literal { version, value });
+                }
+            }
+        }
+
+        let mut children = Vec::new();
+        if reader.read_bit()? {
+            for _ in 0..reader.read(11)? {
+                children.push(Packet::decode(reader)?);
+            }
+        } else {
+            let end = reader.read(15)? as usize + reader.position();
+            while reader.position() < end {
+                children.push(Packet::decode(reader)?);
+            }
+        }
+        Some(Packet::Operator {
+            version,
+            kind,
+            children,
+        })
+    }
+
+    fn encode(&self, writer: &mut BitWriter) {
+        match self {
+            Packet::Literal { version, value } => {
+                writer.push_bits(*version, 3);
+                writer.push_bits(4, 3);
+                let groups = (64 - value.leading_zeros() as usize).div_ceil(4).max(1);
+                for i in (0..groups).rev() {
+                    writer.push(i > 0);
+                    writer.push_bits(value >> (4 * i) & 0xf, 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                kind,
+                children,
+            } => {
+                writer.push_bits(*version, 3);
+                writer.push_bits(*kind, 3);
+                writer.push(true);
+                writer.push_bits(children.len() as u64, 11);
+                children.iter().for_each(|child| child.encode(writer));
+            }
+        }
+    }
+
+    fn to_hex(&self) -> String {
+        let mut writer = BitWriter::new();
+        self.encode(&mut writer);
+        while !writer.len().is_multiple_of(4) {
+            writer.push(false);
+        }
+        let bits = writer.finish();
+        let mut reader = bits.reader();
+        let mut hex: String = std::iter::from_fn(|| reader.read(4))
+            .map(|nibble| format!("{:X}", nibble))
+            .collect();
+        hex.push('\n');
+        hex
+    }
+
+    /// The sub-packets of the operator at `path`, a list of child indices from the root
+    fn children_mut(&mut self, path: &[usize]) -> &mut Vec<Packet> {
+        match self {
+            Packet::Operator { children, .. } => match path.split_first() {
+                None => children,
+                Some((&i, rest)) => children[i].children_mut(rest),
+            },
+            Packet::Literal { .. } => panic!("literal packets have no sub-packets"),
+        }
+    }
+}
+
+/// Removes sub-packets level by level, starting from the outermost packet.
+/// Falls back to single characters if the transmission doesn't decode.
+fn minimize_packets(input: &str, fails: &mut dyn FnMut(&str) -> bool) -> String {
+    let root = BitVec::from_hex(input.trim()).and_then(|bits| Packet::decode(&mut bits.reader()));
+    let Some(mut root) = root else {
+        return minimize_sections(Document::parse(16, input), fails);
+    };
+    // The re-encoded transmission may not fail the same way as the original
+    if !fails(&root.to_hex()) {
+        return minimize_sections(Document::parse(16, input), fails);
+    }
+
+    let mut pending = vec![Vec::new()];
+    while let Some(path) = pending.pop() {
+        let children = root.children_mut(&path).clone();
+        if children.is_empty() {
+            continue;
+        }
+        let kept = ddmin(children, &mut |candidate| {
+            let mut tree = root.clone();
+            *tree.children_mut(&path) = candidate.to_vec();
+            fails(&tree.to_hex())
+        });
+        for (i, child) in kept.iter().enumerate() {
+            if matches!(child, Packet::Operator { .. }) {
+                pending.push([&path[..], &[i]].concat());
+            }
+        }
+        *root.children_mut(&path) = kept;
+    }
+    root.to_hex()
+}
+
+/// The message of the solver's panic on `input`, if it panics
+fn panic_message(day: u8, input: &str) -> Option<String> {
+    let payload = panic::catch_unwind(AssertUnwindSafe(|| crate::solve(day, input))).err()?;
+    Some(
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default(),
+    )
+}
+
+/// Whether the solver and the reference both answer, but differently
+fn mismatch(day: u8, input: &str, reference: &str) -> bool {
+    let answers = match panic::catch_unwind(AssertUnwindSafe(|| crate::solve(day, input))) {
+        Ok(Ok(answers)) => answers,
+        _ => return false,
+    };
+    let mut expected = answers.0.to_string();
+    if let Some(part_two) = answers.1 {
+        expected = format!("{}\n{}", expected, part_two);
+    }
+
+    let child = Command::new("sh")
+        .args(["-c", reference])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        return false;
+    };
+    // The reference may exit without reading everything
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).trim() != expected.trim()
+        }
+        _ => false,
+    }
+}
+
+/// Whether `runner run <day>` is still going after `limit`
+fn times_out(day: u8, input: &str, limit: Duration, runner: &Path) -> bool {
+    let path = std::env::temp_dir().join(format!("aoc-minimize-{}.txt", process::id()));
+    if fs::write(&path, input).is_err() {
+        return false;
+    }
+    let child = Command::new(runner)
+        .args(["run", &day.to_string()])
+        .arg(&path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        return false;
+    };
+
+    let start = Instant::now();
+    let timed_out = loop {
+        match child.try_wait() {
+            Ok(None) if start.elapsed() < limit => thread::sleep(Duration::from_millis(5)),
+            Ok(None) => break true,
+            _ => break false,
+        }
+    };
+    if timed_out {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    let _ = fs::remove_file(&path);
+    timed_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddmin_finds_pair() {
+        let units: Vec<u32> = (0..100).collect();
+        let kept = ddmin(units, &mut |c| c.contains(&17) && c.contains(&83));
+        assert_eq!(kept, [17, 83]);
+    }
+
+    #[test]
+    fn ddmin_removes_everything_irrelevant() {
+        let kept = ddmin(vec![1, 2, 3], &mut |_| true);
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn boards_are_kept_whole() {
+        let board = |n: u32| {
+            (0..5)
+                .map(|row| format!("{0} {0} {0} {0} {1}", n, row))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let input = format!("7,4,9\n\n{}\n\n{}\n\n{}\n", board(10), board(99), board(12));
+        let minimized = minimize_with(4, &input, &mut |candidate| {
+            candidate.contains("99 99") && candidate.starts_with('9')
+        });
+        assert_eq!(minimized.input, format!("9\n\n{}\n", board(99)));
+    }
+
+    #[test]
+    fn template_and_rules() {
+        let input = "NNCB\n\nCH -> B\nHH -> N\nCB -> H\nNN -> C\n";
+        let minimized = minimize_with(14, input, &mut |candidate| {
+            candidate.starts_with("CB\n") && candidate.contains("-> H")
+        });
+        assert_eq!(minimized.input, "CB\n\nCB -> H\n");
+    }
+
+    #[test]
+    fn packets_are_reencoded() {
+        // Sum of 1, an operator over 2 and 3, and 4
+        let tree = Packet::Operator {
+            version: 1,
+            kind: 0,
+            children: vec![
+                Packet::Literal {
+                    version: 2,
+                    value: 1,
+                },
+                Packet::Operator {
+                    version: 3,
+                    kind: 1,
+                    children: vec![
+                        Packet::Literal {
+                            version: 4,
+                            value: 2,
+                        },
+                        Packet::Literal {
+                            version: 5,
+                            value: 300,
+                        },
+                    ],
+                },
+                Packet::Literal {
+                    version: 6,
+                    value: 4,
+                },
+            ],
+        };
+        let hex = tree.to_hex();
+        let bits = BitVec::from_hex(hex.trim()).unwrap();
+        assert_eq!(Packet::decode(&mut bits.reader()), Some(tree));
+
+        let minimized = minimize_with(16, &hex, &mut |candidate| {
+            let bits = BitVec::from_hex(candidate.trim()).unwrap();
+            format!("{:?}", Packet::decode(&mut bits.reader())).contains("value: 300")
+        });
+        let expected = Packet::Operator {
+            version: 1,
+            kind: 0,
+            children: vec![Packet::Operator {
+                version: 3,
+                kind: 1,
+                children: vec![Packet::Literal {
+                    version: 5,
+                    value: 300,
+                }],
+            }],
+        };
+        assert_eq!(minimized.input, expected.to_hex());
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn panic_on_bad_line() {
+        let input = "199\n200\nx\n210\n200\n207";
+        let minimized = minimize(1, input, &Failure::Panic { any: false }).unwrap();
+        assert_eq!(minimized.input, "x\n");
+    }
+
+    #[test]
+    fn only_the_minimising_thread_is_quiet() {
+        let quiet_inside = quietly(|| {
+            let other = thread::spawn(|| QUIET.with(Cell::get)).join().unwrap();
+            (QUIET.with(Cell::get), other)
+        });
+        assert_eq!(quiet_inside, (true, false));
+        assert!(!QUIET.with(Cell::get));
+
+        let escaped = panic::catch_unwind(|| quietly(|| panic!("not caught inside")));
+        assert!(escaped.is_err());
+        assert!(!QUIET.with(Cell::get));
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn input_must_fail() {
+        let result = minimize(1, "1\n2\n3", &Failure::Panic { any: false });
+        assert_eq!(result.err().unwrap(), "Day 1 doesn't panic on this input");
+    }
+}
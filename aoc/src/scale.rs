@@ -0,0 +1,158 @@
+//! Empirical complexity analysis: times a day's solver on generated inputs of
+//! increasing size and fits the growth exponent `k` in `time ~ size^k`.
+
+use crate::generate::{generator, Rng};
+use std::fmt::Write;
+use std::time::Instant;
+
+/// Fitted exponents above this are reported as superlinear
+const SUPERLINEAR: f64 = 1.3;
+const CHART_WIDTH: usize = 40;
+
+pub struct Sample {
+    pub size: usize,
+    /// Fastest of the repeated runs
+    pub micros: f64,
+}
+
+pub struct Scaling {
+    pub day: u8,
+    pub unit: &'static str,
+    pub samples: Vec<Sample>,
+}
+
+/// Solves the day on a generated input for every size, keeping the fastest of
+/// `repeat` runs. Uses the generator's default sizes if `sizes` is empty.
+pub fn measure(day: u8, sizes: &[usize], repeat: usize, seed: u64) -> Result<Scaling, String> {
+    crate::available(day).map_err(|err| err.to_string())?;
+    let generator =
+        generator(day).map_err(|reason| format!("Can't scale day {}: {}", day, reason))?;
+    let sizes = if sizes.is_empty() {
+        generator.sizes
+    } else {
+        sizes
+    };
+
+    let mut samples = Vec::with_capacity(sizes.len());
+    for &size in sizes {
+        let input = (generator.generate)(size, &mut Rng::new(seed));
+        let mut best = f64::INFINITY;
+        for _ in 0..repeat.max(1) {
+            let now = Instant::now();
+            crate::solve(day, &input)
+                .map_err(|err| format!("Day {} failed at size {}: {}", day, size, err))?;
+            best = best.min(now.elapsed().as_secs_f64() * 1e6);
+        }
+        samples.push(Sample { size, micros: best });
+    }
+    Ok(Scaling {
+        day,
+        unit: generator.unit,
+        samples,
+    })
+}
+
+/// Least squares slope of log(time) against log(size)
+pub fn fit_exponent(samples: &[Sample]) -> Option<f64> {
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .filter(|s| s.size > 0 && s.micros > 0.0)
+        .map(|s| ((s.size as f64).ln(), s.micros.ln()))
+        .collect();
+    if points.len() < 2 {
+        return None;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+/// Formats the samples as a table with a bar chart of the times, followed by the fit
+pub fn report(scaling: &Scaling) -> String {
+    let max = scaling.samples.iter().map(|s| s.micros).fold(0.0, f64::max);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Day {:02}, size in {}", scaling.day, scaling.unit);
+    let _ = writeln!(out, "{:>10} {:>14} {:>9}", "size", "time (μs)", "local k");
+
+    for (i, sample) in scaling.samples.iter().enumerate() {
+        let local = match i {
+            0 => "-".to_string(),
+            _ => fit_exponent(&scaling.samples[i - 1..=i])
+                .map_or("-".to_string(), |k| format!("{:.2}", k)),
+        };
+        let bar = if max > 0.0 {
+            ((sample.micros / max) * CHART_WIDTH as f64).round() as usize
+        } else {
+            0
+        };
+        let _ = writeln!(
+            out,
+            "{:>10} {:>14.1} {:>9}  {}",
+            sample.size,
+            sample.micros,
+            local,
+            "#".repeat(bar.max(1))
+        );
+    }
+
+    match fit_exponent(&scaling.samples) {
+        Some(k) => {
+            let _ = writeln!(out, "Fitted growth: time ~ size^{:.2}", k);
+            if k > SUPERLINEAR {
+                let _ = writeln!(out, "Warning: superlinear in {}", scaling.unit);
+            }
+        }
+        None => {
+            let _ = writeln!(out, "Not enough samples to fit the growth");
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(f: fn(f64) -> f64) -> Vec<Sample> {
+        [10, 20, 40, 80]
+            .iter()
+            .map(|&size| Sample {
+                size,
+                micros: f(size as f64),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn fit_linear() {
+        let k = fit_exponent(&samples(|n| 3.0 * n)).unwrap();
+        assert!((k - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fit_quadratic() {
+        let k = fit_exponent(&samples(|n| 0.5 * n * n)).unwrap();
+        assert!((k - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn fit_needs_two_sizes() {
+        assert_eq!(fit_exponent(&samples(|n| n)[..1]), None);
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        for day in crate::days() {
+            if let Ok(generator) = generator(day) {
+                let input = (generator.generate)(generator.sizes[0], &mut Rng::new(1));
+                assert!(crate::solve(day, &input).is_ok(), "day {}", day);
+            }
+        }
+    }
+}
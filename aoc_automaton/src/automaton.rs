@@ -0,0 +1,245 @@
+use crate::{Boundary, Neighbourhood, Rule};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+/// A grid of cells stepped a generation at a time
+#[derive(Clone, Debug)]
+pub struct Automaton<C> {
+    width: usize,
+    height: usize,
+    cells: Vec<C>,
+    /// The buffer the next generation is written to
+    next: Vec<C>,
+    boundary: Boundary<C>,
+    generation: usize,
+}
+
+/// A state repeating every `period` generations from generation `start` on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl<C: Clone + PartialEq> Automaton<C> {
+    /// Panics if the rows are empty or not all the same length
+    pub fn new(rows: Vec<Vec<C>>, boundary: Boundary<C>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(width > 0, "the grid is empty");
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "the rows are not all the same length"
+        );
+
+        Automaton {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+            next: Vec::with_capacity(width * height),
+            boundary,
+            generation: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of steps taken so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// The state of every cell beyond the grid, if it has an infinite background
+    pub fn background(&self) -> Option<&C> {
+        match &self.boundary {
+            Boundary::Infinite(background) => Some(background),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&C> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    /// Every cell of the grid, in rows from the top left
+    pub fn cells(&self) -> impl Iterator<Item = &C> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[C]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// Changes every cell of the grid in place, outside of any step
+    pub fn update(&mut self, mut f: impl FnMut(&mut C)) {
+        self.cells.iter_mut().for_each(&mut f);
+    }
+
+    /// Steps every cell to the next generation, returns whether anything changed
+    pub fn step<R: Rule<C>>(&mut self, rule: &R) -> bool {
+        if let Boundary::Infinite(background) = &self.boundary {
+            self.grow(background.clone());
+        }
+
+        self.next.clear();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let neighbourhood = self.neighbourhood(row, col);
+                self.next.push(rule.next(&neighbourhood));
+            }
+        }
+        let mut changed = self.cells != self.next;
+        mem::swap(&mut self.cells, &mut self.next);
+
+        if let Boundary::Infinite(background) = &mut self.boundary {
+            let next = rule.next(&Neighbourhood::new([Some(&*background); 9]));
+            changed |= next != *background;
+            *background = next;
+        }
+        self.generation += 1;
+        changed
+    }
+
+    /// Takes `steps` steps
+    pub fn steps<R: Rule<C>>(&mut self, rule: &R, steps: usize) {
+        for _ in 0..steps {
+            self.step(rule);
+        }
+    }
+
+    /// Steps until nothing changes any more, returns the number of steps that changed something
+    pub fn settle<R: Rule<C>>(&mut self, rule: &R) -> usize {
+        let mut steps = 0;
+        while self.step(rule) {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Surrounds the grid with a border of `background` cells
+    fn grow(&mut self, background: C) {
+        let width = self.width + 2;
+        let mut grown = Vec::with_capacity(width * (self.height + 2));
+        grown.resize(width, background.clone());
+        for row in self.cells.chunks(self.width) {
+            grown.push(background.clone());
+            grown.extend_from_slice(row);
+            grown.push(background.clone());
+        }
+        grown.resize(width * (self.height + 2), background);
+
+        self.cells = grown;
+        self.width = width;
+        self.height += 2;
+    }
+
+    fn neighbourhood(&self, row: usize, col: usize) -> Neighbourhood<'_, C> {
+        let mut cells = [None; 9];
+        for (i, cell) in cells.iter_mut().enumerate() {
+            let row = self.wrap(row as isize + i as isize / 3 - 1, self.height);
+            let col = self.wrap(col as isize + i as isize % 3 - 1, self.width);
+            *cell = match (row, col, &self.boundary) {
+                (Some(row), Some(col), _) => Some(&self.cells[row * self.width + col]),
+                (_, _, Boundary::Infinite(background)) => Some(background),
+                _ => None,
+            };
+        }
+        Neighbourhood::new(cells)
+    }
+
+    /// The index along an axis of length `len`, or None if it's off the grid
+    fn wrap(&self, i: isize, len: usize) -> Option<usize> {
+        match self.boundary {
+            Boundary::Toroidal => Some(i.rem_euclid(len as isize) as usize),
+            _ => (0..len as isize).contains(&i).then_some(i as usize),
+        }
+    }
+}
+
+impl<C: Clone + Eq + Hash> Automaton<C> {
+    /// Steps until the grid returns to an earlier state, giving up after `limit` steps.
+    /// Grids with an infinite background grow every step, so they never repeat.
+    pub fn find_cycle<R: Rule<C>>(&mut self, rule: &R, limit: usize) -> Option<Cycle> {
+        let mut seen = HashMap::from([(self.cells.clone(), self.generation)]);
+        for _ in 0..limit {
+            self.step(rule);
+            if let Some(&start) = seen.get(&self.cells) {
+                return Some(Cycle {
+                    start,
+                    period: self.generation - start,
+                });
+            }
+            seen.insert(self.cells.clone(), self.generation);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(neighbourhood: &Neighbourhood<bool>) -> bool {
+        let alive = neighbourhood.neighbours().filter(|&&alive| alive).count();
+        alive == 3 || (alive == 2 && *neighbourhood.centre())
+    }
+
+    fn grid(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect()
+    }
+
+    #[test]
+    fn blinker_cycles() {
+        let rows = grid(&[".....", ".....", ".###.", ".....", "....."]);
+        let mut automaton = Automaton::new(rows, Boundary::Finite);
+        assert_eq!(
+            automaton.find_cycle(&life, 10),
+            Some(Cycle {
+                start: 0,
+                period: 2
+            })
+        );
+    }
+
+    #[test]
+    fn glider_wraps_around_torus() {
+        let rows = grid(&[".#....", "..#...", "###...", "......", "......", "......"]);
+        let mut automaton = Automaton::new(rows.clone(), Boundary::Toroidal);
+        // A glider moves one cell diagonally every 4 steps
+        automaton.steps(&life, 4 * 6);
+        assert!(automaton.rows().eq(rows.iter().map(Vec::as_slice)));
+    }
+
+    #[test]
+    fn settle_stops_on_still_life() {
+        let rows = grid(&["....", ".##.", ".#..", "...."]);
+        let mut automaton = Automaton::new(rows, Boundary::Finite);
+        assert_eq!(automaton.settle(&life), 1);
+        assert_eq!(automaton.cells().filter(|&&alive| alive).count(), 4);
+    }
+
+    #[test]
+    fn infinite_background_flips() {
+        // Every cell flips, so the whole plane alternates between dark and lit
+        let flip = |neighbourhood: &Neighbourhood<bool>| !neighbourhood.centre();
+        let mut automaton = Automaton::new(grid(&["#"]), Boundary::Infinite(false));
+
+        automaton.step(&flip);
+        assert_eq!(automaton.background(), Some(&true));
+        assert_eq!(automaton.width(), 3);
+        assert_eq!(automaton.cells().filter(|&&lit| lit).count(), 8);
+
+        automaton.step(&flip);
+        assert_eq!(automaton.background(), Some(&false));
+        assert_eq!(automaton.cells().filter(|&&lit| lit).count(), 1);
+    }
+}
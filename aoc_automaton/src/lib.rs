@@ -0,0 +1,37 @@
+//! Cellular automata on 2D grids.
+//!
+//! An [`Automaton`] holds the grid and how its edges behave, and is stepped by
+//! a [`Rule`] giving the next state of a cell from its 3x3 [`Neighbourhood`].
+//! Every cell is computed from the previous generation, into a second buffer.
+
+mod automaton;
+mod neighbourhood;
+
+pub use automaton::{Automaton, Cycle};
+pub use neighbourhood::Neighbourhood;
+
+/// What lies beyond the edges of the grid
+#[derive(Clone, Debug, PartialEq)]
+pub enum Boundary<C> {
+    /// Nothing, cells on the edges have fewer neighbours
+    Finite,
+    /// The grid wraps around, the cells on opposite edges are neighbours
+    Toroidal,
+    /// An infinite background of cells all in the given state. The background
+    /// is stepped by the rule like any other cell, and the grid grows by one
+    /// cell on every side each step to hold the cells that may now differ from it.
+    Infinite(C),
+}
+
+/// How a cell in state `C` changes from one generation to the next.
+/// Implemented by functions from the neighbourhood to the next state.
+pub trait Rule<C> {
+    /// The next state of the cell in the centre of `neighbourhood`
+    fn next(&self, neighbourhood: &Neighbourhood<C>) -> C;
+}
+
+impl<C, F: Fn(&Neighbourhood<C>) -> C> Rule<C> for F {
+    fn next(&self, neighbourhood: &Neighbourhood<C>) -> C {
+        self(neighbourhood)
+    }
+}
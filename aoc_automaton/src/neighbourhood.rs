@@ -0,0 +1,30 @@
+/// A cell and the eight cells around it, in rows from the top left.
+/// Cells beyond the edge of a finite grid are missing.
+pub struct Neighbourhood<'a, C> {
+    cells: [Option<&'a C>; 9],
+}
+
+impl<'a, C> Neighbourhood<'a, C> {
+    pub(crate) fn new(cells: [Option<&'a C>; 9]) -> Self {
+        Neighbourhood { cells }
+    }
+
+    /// The cell being stepped
+    pub fn centre(&self) -> &'a C {
+        self.cells[4].unwrap()
+    }
+
+    /// The up to eight cells around the centre
+    pub fn neighbours(&self) -> impl Iterator<Item = &'a C> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 4)
+            .filter_map(|(_, cell)| *cell)
+    }
+
+    /// All nine cells in rows from the top left, the centre included
+    pub fn window(&self) -> &[Option<&'a C>; 9] {
+        &self.cells
+    }
+}
@@ -0,0 +1,79 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough for every unsigned and signed answer
+    Integer(i128),
+    Text(String),
+    /// Rows of characters, such as the letters drawn by day 13 part two
+    Grid(Vec<Vec<char>>),
+}
+
+/// The answers to part one and part two.
+/// Part two is `None` for days that only have part one solved.
+pub type Answers = (Answer, Option<Answer>);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => {
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    row.iter().try_for_each(|c| write!(f, "{}", c))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Integer(n.into())
+            }
+        })*
+    };
+}
+impl_from_integer!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<Vec<Vec<char>>> for Answer {
+    fn from(rows: Vec<Vec<char>>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_integer() {
+        assert_eq!(Answer::from(1644286074024u64).to_string(), "1644286074024");
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+    }
+
+    #[test]
+    fn display_grid() {
+        let grid = vec![vec!['#', '.', '#'], vec!['.', '#', '.']];
+        assert_eq!(Answer::from(grid).to_string(), "#.#\n.#.");
+    }
+}
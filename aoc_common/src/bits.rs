@@ -0,0 +1,227 @@
+//! Bit manipulation for the days reading binary, most significant bit first.
+
+use std::fmt;
+
+const WORD: usize = u64::BITS as usize;
+
+/// A fixed number of bits, indexed from the most significant
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// `len` unset bits
+    pub fn zeros(len: usize) -> Self {
+        BitVec {
+            words: vec![0; len.div_ceil(WORD)],
+            len,
+        }
+    }
+
+    /// Parses a string of `0` and `1`, returns None on any other character
+    pub fn from_binary(binary: &str) -> Option<Self> {
+        let mut writer = BitWriter::new();
+        for c in binary.bytes() {
+            match c {
+                b'0' => writer.push(false),
+                b'1' => writer.push(true),
+                _ => return None,
+            }
+        }
+        Some(writer.finish())
+    }
+
+    /// Parses hexadecimal digits into 4 bits each, returns None on any other character
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let mut writer = BitWriter::new();
+        for c in hex.chars() {
+            writer.push_bits(c.to_digit(16)? as u64, 4);
+        }
+        Some(writer.finish())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Panics if `i` is out of bounds
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        self.words[i / WORD] >> (WORD - 1 - i % WORD) & 1 == 1
+    }
+
+    /// Panics if `i` is out of bounds
+    pub fn set(&mut self, i: usize, bit: bool) {
+        assert!(i < self.len, "bit {} out of {}", i, self.len);
+        let mask = 1 << (WORD - 1 - i % WORD);
+        if bit {
+            self.words[i / WORD] |= mask;
+        } else {
+            self.words[i / WORD] &= !mask;
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The bits as an unsigned number, None if there are more than 64
+    pub fn to_u64(&self) -> Option<u64> {
+        (self.len <= WORD).then(|| self.reader().read(self.len).unwrap())
+    }
+
+    /// Reads the bits in order from the first
+    pub fn reader(&self) -> BitReader<'_> {
+        BitReader {
+            bits: self,
+            position: 0,
+        }
+    }
+}
+
+impl fmt::Display for BitVec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.iter()
+            .try_for_each(|bit| write!(f, "{}", if bit { '1' } else { '0' }))
+    }
+}
+
+/// Reads a `BitVec` from the most significant bit on
+pub struct BitReader<'a> {
+    bits: &'a BitVec,
+    position: usize,
+}
+
+impl BitReader<'_> {
+    /// The number of bits read so far
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.bits.len - self.position
+    }
+
+    pub fn read_bit(&mut self) -> Option<bool> {
+        let bit = (self.position < self.bits.len).then(|| self.bits.get(self.position));
+        self.position += bit.is_some() as usize;
+        bit
+    }
+
+    /// Reads the next `count` bits as an unsigned number, None if fewer are left.
+    /// Panics if `count` is more than 64.
+    pub fn read(&mut self, count: usize) -> Option<u64> {
+        assert!(count <= WORD, "can't read {} bits into a u64", count);
+        if count > self.remaining() {
+            return None;
+        }
+        Some(accumulate((0..count).map(|_| self.read_bit().unwrap())))
+    }
+}
+
+/// Builds a `BitVec` by appending bits after the last
+#[derive(Default)]
+pub struct BitWriter {
+    bits: BitVec,
+}
+
+impl BitWriter {
+    pub fn new() -> Self {
+        BitWriter::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.len == 0
+    }
+
+    pub fn push(&mut self, bit: bool) {
+        if self.bits.len.is_multiple_of(WORD) {
+            self.bits.words.push(0);
+        }
+        self.bits.len += 1;
+        self.bits.set(self.bits.len - 1, bit);
+    }
+
+    /// Appends the lowest `count` bits of `value`, most significant first
+    pub fn push_bits(&mut self, value: u64, count: usize) {
+        for i in (0..count).rev() {
+            self.push(value >> i & 1 == 1);
+        }
+    }
+
+    pub fn finish(self) -> BitVec {
+        self.bits
+    }
+}
+
+/// Shifts the bits into a number, the first becoming the most significant.
+/// Wraps around if there are more than 64.
+pub fn accumulate(bits: impl IntoIterator<Item = bool>) -> u64 {
+    bits.into_iter()
+        .fold(0, |value, bit| value << 1 | u64::from(bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_round_trip() {
+        let bits = BitVec::from_binary("110110101101").unwrap();
+        assert_eq!(bits.to_u64(), Some(3501));
+        assert_eq!(bits.to_string(), "110110101101");
+        assert_eq!(bits.count_ones(), 8);
+        assert_eq!(BitVec::from_binary("10a"), None);
+    }
+
+    #[test]
+    fn hex_spans_words() {
+        let bits = BitVec::from_hex("D2FE28D2FE28D2FE28").unwrap();
+        assert_eq!(bits.len(), 72);
+        assert_eq!(bits.to_u64(), None);
+        assert!(!bits.get(64) && bits.get(66));
+        assert_eq!(
+            BitVec::from_hex("D2FE28").unwrap().to_string(),
+            "110100101111111000101000"
+        );
+    }
+
+    #[test]
+    fn reader_reads_fields() {
+        let bits = BitVec::from_hex("D2FE28").unwrap();
+        let mut reader = bits.reader();
+        assert_eq!(reader.read(3), Some(6));
+        assert_eq!(reader.read(3), Some(4));
+        assert_eq!(reader.read_bit(), Some(true));
+        assert_eq!(reader.position(), 7);
+        assert_eq!(reader.read(18), None);
+        assert_eq!(reader.read(17), Some(0b0_1111_1110_0010_1000));
+        assert_eq!(reader.read_bit(), None);
+    }
+
+    #[test]
+    fn set_and_accumulate() {
+        let mut bits = BitVec::zeros(9);
+        bits.set(0, true);
+        bits.set(8, true);
+        assert_eq!(bits.to_u64(), Some(0b1_0000_0001));
+        assert_eq!(accumulate(bits.iter()), 0b1_0000_0001);
+    }
+}
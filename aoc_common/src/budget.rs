@@ -0,0 +1,172 @@
+//! Limits on how long a solver may run.
+//!
+//! Long-running loops call [`tick`] once per iteration. Outside of [`limit`]
+//! ticking never fails, so solvers run unbounded unless a caller sets a budget.
+//! Iterations are counted per loop, so nested loops can share a budget.
+
+use crate::Error;
+use std::cell::RefCell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The wall time a solver may use and the iterations each of its loops may
+/// run, unlimited by default
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    pub iterations: Option<u64>,
+}
+
+/// How far a solver got before it ran out of budget
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    /// The loop that was running
+    pub task: &'static str,
+    /// Iterations of that loop ticked so far
+    pub iterations: u64,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} after {} iterations in {:?}",
+            self.task, self.iterations, self.elapsed
+        )
+    }
+}
+
+/// The budget being spent on this thread
+struct Meter {
+    budget: Budget,
+    start: Instant,
+    /// Iterations ticked by each loop, in the order they first ticked
+    iterations: Vec<(&'static str, u64)>,
+}
+
+thread_local! {
+    static METER: RefCell<Option<Meter>> = const { RefCell::new(None) };
+}
+
+/// Puts back the budget that was active before `limit`, even if `f` panics
+struct Restore(Option<Meter>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        METER.set(self.0.take());
+    }
+}
+
+/// Runs `f`, making every `tick` on this thread fail once `budget` is spent.
+/// Each loop's iterations add up over the whole of `f`: an inner loop run once
+/// per outer iteration has its ticks from every run counted together, so its
+/// budget must cover all of them, not just one run.
+pub fn limit<T>(budget: Budget, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(METER.replace(Some(Meter {
+        budget,
+        start: Instant::now(),
+        iterations: Vec::new(),
+    })));
+    f()
+}
+
+/// Counts one iteration of the loop `task`, failing if that exceeds the budget.
+/// The count is only reset when `limit` returns, not when the loop starts over.
+pub fn tick(task: &'static str) -> Result<(), Error> {
+    METER.with_borrow_mut(|meter| {
+        let Some(meter) = meter else {
+            return Ok(());
+        };
+        let iterations = match meter.iterations.iter_mut().find(|(name, _)| *name == task) {
+            Some((_, iterations)) => iterations,
+            None => {
+                meter.iterations.push((task, 0));
+                &mut meter.iterations.last_mut().unwrap().1
+            }
+        };
+        *iterations += 1;
+        let iterations = *iterations;
+
+        let elapsed = meter.start.elapsed();
+        let out_of_time = meter.budget.time.is_some_and(|time| elapsed > time);
+        let out_of_iterations = meter.budget.iterations.is_some_and(|max| iterations > max);
+
+        if out_of_time || out_of_iterations {
+            return Err(Error::BudgetExceeded(Progress {
+                task,
+                iterations,
+                elapsed,
+            }));
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_to(n: u64) -> Result<u64, Error> {
+        for _ in 0..n {
+            tick("count_to")?;
+        }
+        Ok(n)
+    }
+
+    #[test]
+    fn unlimited_outside_limit() {
+        assert_eq!(count_to(1000), Ok(1000));
+    }
+
+    #[test]
+    fn iterations_run_out() {
+        let budget = Budget {
+            iterations: Some(10),
+            ..Budget::default()
+        };
+        assert_eq!(limit(budget, || count_to(10)), Ok(10));
+
+        let err = limit(budget, || count_to(11)).unwrap_err();
+        let Error::BudgetExceeded(progress) = err else {
+            panic!("unexpected error {}", err);
+        };
+        assert_eq!((progress.task, progress.iterations), ("count_to", 11));
+        // The budget ends with `limit`
+        assert_eq!(count_to(11), Ok(11));
+    }
+
+    #[test]
+    fn loops_count_separately() {
+        let budget = Budget {
+            iterations: Some(10),
+            ..Budget::default()
+        };
+        // 20 iterations in all, but only 10 of each loop until the last
+        let nested = || {
+            for _ in 0..10 {
+                tick("outer")?;
+                tick("inner")?;
+            }
+            tick("outer")
+        };
+        let err = limit(budget, nested).unwrap_err();
+        let Error::BudgetExceeded(progress) = err else {
+            panic!("unexpected error {}", err);
+        };
+        assert_eq!((progress.task, progress.iterations), ("outer", 11));
+    }
+
+    #[test]
+    fn time_runs_out() {
+        let budget = Budget {
+            time: Some(Duration::ZERO),
+            ..Budget::default()
+        };
+        let result = limit(budget, || {
+            std::thread::sleep(Duration::from_millis(1));
+            count_to(1)
+        });
+        assert!(matches!(result, Err(Error::BudgetExceeded(_))));
+    }
+}
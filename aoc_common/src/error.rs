@@ -0,0 +1,40 @@
+use crate::budget::Progress;
+use crate::Violation;
+use std::fmt;
+
+/// Why a solver could not produce an answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// There is no path from the start to the target
+    NoPath,
+    /// No bingo board wins with the numbers drawn
+    NoWinner,
+    /// A loop ran out of the budget set with `budget::limit`
+    BudgetExceeded(Progress),
+    /// A parameter the day doesn't have
+    UnknownParam(String),
+    /// A parameter value the solver can't use
+    InvalidParam(String, u64),
+    /// The input couldn't be parsed
+    InvalidInput(Violation),
+    /// A value outgrew the solver's integers, described by the message
+    Overflow(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::NoPath => write!(f, "no path from the start to the target"),
+            Error::NoWinner => write!(f, "no board wins with the numbers drawn"),
+            Error::BudgetExceeded(progress) => write!(f, "budget exceeded in {}", progress),
+            Error::UnknownParam(name) => write!(f, "unknown parameter {}", name),
+            Error::InvalidParam(name, value) => {
+                write!(f, "invalid value {} for parameter {}", value, name)
+            }
+            Error::InvalidInput(violation) => write!(f, "invalid input at {}", violation),
+            Error::Overflow(message) => write!(f, "overflow: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
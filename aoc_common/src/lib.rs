@@ -0,0 +1,11 @@
+mod answer;
+pub mod bits;
+pub mod budget;
+mod error;
+pub mod params;
+mod violation;
+
+pub use answer::{Answer, Answers};
+pub use error::Error;
+pub use params::Params;
+pub use violation::Violation;
@@ -0,0 +1,91 @@
+//! Named parameters overriding the puzzle constants baked into the solvers.
+//!
+//! Days with parameters have a `Config` struct holding the puzzle's values by
+//! default, built from `Params` with `Config::from_params`.
+
+use crate::Error;
+use std::collections::BTreeMap;
+use std::ops::RangeBounds;
+
+/// Parameter values by name, all unset by default
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, u64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: u64) {
+        self.0.insert(name.into(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.0.iter().map(|(name, &value)| (name.as_str(), value))
+    }
+
+    /// Fails on the first parameter that isn't one of `known`
+    pub fn only(&self, known: &[&str]) -> Result<(), Error> {
+        match self.0.keys().find(|name| !known.contains(&name.as_str())) {
+            Some(name) => Err(Error::UnknownParam(name.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of `name`, or `default` if it isn't set.
+    /// Fails if the value doesn't fit in `T` or is outside `range`.
+    pub fn get<T>(&self, name: &str, default: T, range: impl RangeBounds<T>) -> Result<T, Error>
+    where
+        T: TryFrom<u64> + PartialOrd,
+    {
+        let Some(&value) = self.0.get(name) else {
+            return Ok(default);
+        };
+        match T::try_from(value) {
+            Ok(value) if range.contains(&value) => Ok(value),
+            _ => Err(Error::InvalidParam(name.to_string(), value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_and_overrides() {
+        let mut params = Params::new();
+        assert_eq!(params.get("steps", 10u16, 1..), Ok(10));
+        params.set("steps", 40);
+        assert_eq!(params.get("steps", 10u16, 1..), Ok(40));
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        let mut params = Params::new();
+        params.set("steps", 70_000);
+        assert_eq!(
+            params.get("steps", 10u16, 1..),
+            Err(Error::InvalidParam("steps".to_string(), 70_000))
+        );
+        params.set("steps", 0);
+        assert_eq!(
+            params.get("steps", 10u16, 1..),
+            Err(Error::InvalidParam("steps".to_string(), 0))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        let mut params = Params::new();
+        params.set("step", 40);
+        assert_eq!(
+            params.only(&["steps"]),
+            Err(Error::UnknownParam("step".to_string()))
+        );
+    }
+}
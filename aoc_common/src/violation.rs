@@ -0,0 +1,52 @@
+use std::fmt;
+
+/// A way an input breaks the assumptions of its solver
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number, starting at 1, if the violation is within the line
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Violation {
+    /// A violation at the 0-based line `index`
+    pub fn line(index: usize, message: impl Into<String>) -> Self {
+        Violation {
+            line: index + 1,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// A violation at `token`, which must be a slice of `line`, on the 0-based line `index`
+    pub fn token(index: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        Violation {
+            column: Some(token.as_ptr() as usize - line.as_ptr() as usize + 1),
+            ..Violation::line(index, message)
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "{}:{}: {}", self.line, column, self.message),
+            None => write!(f, "{}: {}", self.line, self.message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_column() {
+        let line = "1,2 -> 3,4";
+        let violation = Violation::token(2, line, &line[7..8], "bad");
+        assert_eq!(violation.to_string(), "3:8: bad");
+        assert_eq!(Violation::line(0, "bad").to_string(), "1: bad");
+    }
+}
@@ -0,0 +1,21 @@
+use std::env;
+
+/// Set to also update the committed `include/aoc.h`, which the `c_abi` test
+/// checks is current
+const UPDATE_HEADER: &str = "AOC_FFI_UPDATE_HEADER";
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed={}", UPDATE_HEADER);
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    // Written to OUT_DIR so that building doesn't change the source tree
+    let header = cbindgen::generate(&crate_dir).expect("Unable to generate C header");
+    header.write_to_file(format!("{}/aoc.h", out_dir));
+    if env::var_os(UPDATE_HEADER).is_some() {
+        header.write_to_file(format!("{}/include/aoc.h", crate_dir));
+    }
+}
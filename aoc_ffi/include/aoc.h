@@ -0,0 +1,61 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated by cbindgen from aoc_ffi/src/lib.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Size of each answer buffer, including the terminating nul byte
+ */
+#define AOC_ANSWER_CAPACITY 512
+
+/**
+ * Error code returned by `aoc_solve`
+ */
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_NULL_POINTER = 1,
+  AOC_STATUS_INVALID_UTF8 = 2,
+  AOC_STATUS_UNKNOWN_DAY = 3,
+  AOC_STATUS_SOLVER_PANICKED = 4,
+  AOC_STATUS_ANSWER_TOO_LONG = 5,
+  AOC_STATUS_SOLVER_FAILED = 6,
+  AOC_STATUS_DAY_NOT_COMPILED = 7,
+} AocStatus;
+
+/**
+ * Nul-terminated answers to both parts of a day.
+ * Part two is left empty for days that only have part one solved.
+ */
+typedef struct AocAnswers {
+  char part_one[AOC_ANSWER_CAPACITY];
+  char part_two[AOC_ANSWER_CAPACITY];
+} AocAnswers;
+
+/**
+ * Solves both parts of `day` for the `input_len` bytes of UTF-8 at `input_ptr`,
+ * writing the answers to `out_buf`. `out_buf` is only written on `AOC_STATUS_OK`.
+ *
+ * # Safety
+ *
+ * `input_ptr` must point to `input_len` readable bytes and `out_buf` must point
+ * to a writable `AocAnswers`.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         const uint8_t *input_ptr,
+                         size_t input_len,
+                         struct AocAnswers *out_buf);
+
+/**
+ * Returns a static, nul-terminated description of the `AocStatus` code `status`.
+ * Takes an `int`, as C can pass any, and describes codes that aren't statuses
+ * as unknown.
+ */
+const char *aoc_status_message(int status);
+
+#endif  /* AOC_H */
@@ -0,0 +1,176 @@
+//! C ABI for calling the solvers in-process from other languages.
+//! The header in `include/aoc.h` is generated from this file by the build script
+//! when built with `AOC_FFI_UPDATE_HEADER` set.
+
+use std::ffi::{c_char, c_int, CStr};
+use std::panic;
+use std::slice;
+use std::str;
+
+/// Size of each answer buffer, including the terminating nul byte
+pub const AOC_ANSWER_CAPACITY: usize = 512;
+
+/// Nul-terminated answers to both parts of a day.
+/// Part two is left empty for days that only have part one solved.
+#[repr(C)]
+pub struct AocAnswers {
+    pub part_one: [c_char; AOC_ANSWER_CAPACITY],
+    pub part_two: [c_char; AOC_ANSWER_CAPACITY],
+}
+
+/// Error code returned by `aoc_solve`
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AocStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    UnknownDay = 3,
+    SolverPanicked = 4,
+    AnswerTooLong = 5,
+    SolverFailed = 6,
+    DayNotCompiled = 7,
+}
+
+/// Solves both parts of `day` for the `input_len` bytes of UTF-8 at `input_ptr`,
+/// writing the answers to `out_buf`. `out_buf` is only written on `AOC_STATUS_OK`.
+///
+/// # Safety
+///
+/// `input_ptr` must point to `input_len` readable bytes and `out_buf` must point
+/// to a writable `AocAnswers`.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    input_ptr: *const u8,
+    input_len: usize,
+    out_buf: *mut AocAnswers,
+) -> AocStatus {
+    if input_ptr.is_null() || out_buf.is_null() {
+        return AocStatus::NullPointer;
+    }
+    let input = match str::from_utf8(slice::from_raw_parts(input_ptr, input_len)) {
+        Ok(input) => input,
+        Err(_) => return AocStatus::InvalidUtf8,
+    };
+
+    // Solvers panic on malformed input, which must not unwind into the caller
+    let answers = match panic::catch_unwind(|| aoc::solve(day, input)) {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(aoc::Error::UnknownDay(_))) => return AocStatus::UnknownDay,
+        Ok(Err(aoc::Error::NotCompiled(_))) => return AocStatus::DayNotCompiled,
+        Ok(Err(aoc::Error::Solver(_))) => return AocStatus::SolverFailed,
+        Err(_) => return AocStatus::SolverPanicked,
+    };
+
+    let mut part_one = [0; AOC_ANSWER_CAPACITY];
+    let mut part_two = [0; AOC_ANSWER_CAPACITY];
+    let part_two_answer = answers.1.map_or(String::new(), |answer| answer.to_string());
+    if !copy_answer(&answers.0.to_string(), &mut part_one)
+        || !copy_answer(&part_two_answer, &mut part_two)
+    {
+        return AocStatus::AnswerTooLong;
+    }
+
+    let out = &mut *out_buf;
+    out.part_one = part_one;
+    out.part_two = part_two;
+    AocStatus::Ok
+}
+
+/// Returns a static, nul-terminated description of the `AocStatus` code `status`.
+/// Takes an `int`, as C can pass any, and describes codes that aren't statuses
+/// as unknown.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: c_int) -> *const c_char {
+    let message: &'static CStr = match status {
+        0 => c"ok",
+        1 => c"input or output pointer is null",
+        2 => c"input is not valid UTF-8",
+        3 => c"no solver for this day",
+        4 => c"solver panicked, the input is probably malformed",
+        5 => c"answer does not fit in AOC_ANSWER_CAPACITY bytes",
+        6 => c"solver found no answer for this input",
+        7 => c"the solver for this day was not compiled in",
+        _ => c"unknown status",
+    };
+    message.as_ptr()
+}
+
+/// Copies `answer` into `buf` with a terminating nul, returns false if it doesn't fit
+fn copy_answer(answer: &str, buf: &mut [c_char; AOC_ANSWER_CAPACITY]) -> bool {
+    if answer.len() >= AOC_ANSWER_CAPACITY {
+        return false;
+    }
+    for (dst, &src) in buf.iter_mut().zip(answer.as_bytes()) {
+        *dst = src as c_char;
+    }
+    buf[answer.len()] = 0;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answers() -> AocAnswers {
+        AocAnswers {
+            part_one: [0; AOC_ANSWER_CAPACITY],
+            part_two: [0; AOC_ANSWER_CAPACITY],
+        }
+    }
+
+    #[cfg(feature = "day17")]
+    fn to_str(buf: &[c_char]) -> &str {
+        unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "day17")]
+    fn solve_example() {
+        let input = "target area: x=20..30, y=-10..-5";
+        let mut out = answers();
+        let status = unsafe { aoc_solve(17, input.as_ptr(), input.len(), &mut out) };
+
+        assert_eq!(status, AocStatus::Ok);
+        assert_eq!(to_str(&out.part_one), "45");
+        assert_eq!(to_str(&out.part_two), "112");
+    }
+
+    #[test]
+    fn status_messages() {
+        // The messages are static
+        let message = |status| unsafe { CStr::from_ptr(aoc_status_message(status)) }.to_str();
+        assert_eq!(message(AocStatus::Ok as c_int), Ok("ok"));
+        assert_eq!(
+            message(AocStatus::DayNotCompiled as c_int),
+            Ok("the solver for this day was not compiled in")
+        );
+        assert_eq!(message(8), Ok("unknown status"));
+        assert_eq!(message(-1), Ok("unknown status"));
+    }
+
+    #[test]
+    fn unknown_day() {
+        let mut out = answers();
+        let status = unsafe { aoc_solve(19, "".as_ptr(), 0, &mut out) };
+        assert_eq!(status, AocStatus::UnknownDay);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let input = [0xff, 0xfe];
+        let mut out = answers();
+        let status = unsafe { aoc_solve(1, input.as_ptr(), input.len(), &mut out) };
+        assert_eq!(status, AocStatus::InvalidUtf8);
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn solver_panic_is_caught() {
+        let input = "not a number";
+        let mut out = answers();
+        let status = unsafe { aoc_solve(1, input.as_ptr(), input.len(), &mut out) };
+        assert_eq!(status, AocStatus::SolverPanicked);
+    }
+}
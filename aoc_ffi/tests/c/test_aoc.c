@@ -0,0 +1,139 @@
+/* Links against libaoc_ffi and checks the answers for the puzzle examples. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+struct example {
+    uint8_t day;
+    const char *input;
+    const char *part_one;
+    const char *part_two;
+};
+
+static const struct example EXAMPLES[] = {
+    {1, "199\n200\n208\n210\n200\n207\n240\n269\n260\n263", "7", "5"},
+    {2, "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2", "150", "900"},
+    {3,
+     "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010",
+     "198", "230"},
+    {4,
+     "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1\n"
+     "\n"
+     "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19\n"
+     "\n"
+     " 3 15  0  2 22\n 9 18 13 17  5\n19  8  7 25 23\n20 11 10 24  4\n14 21 16 12  6\n"
+     "\n"
+     "14 21 17 24  4\n10 16 15  9 19\n18  8 23 26 20\n22 11 13  6  5\n 2  0 12  3  7",
+     "4512", "1924"},
+    {5,
+     "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n"
+     "3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2",
+     "5", "12"},
+    {6, "3,4,3,1,2", "5934", "26984457539"},
+    {7, "16,1,2,0,4,2,7,1,2,14", "37", "168"},
+    {8,
+     "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe\n"
+     "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc\n"
+     "fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg\n"
+     "fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb\n"
+     "aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea\n"
+     "fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb\n"
+     "dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe\n"
+     "bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef\n"
+     "egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb\n"
+     "gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce",
+     "26", "61229"},
+    {9, "2199943210\n3987894921\n9856789892\n8767896789\n9899965678", "15", "1134"},
+    {10,
+     "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n"
+     "(((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n"
+     "{<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n"
+     "<{([{{}}[<[[[<>{}]]]>[]]",
+     "26397", "288957"},
+    {11,
+     "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n"
+     "4167524645\n2176841721\n6882881134\n4846848554\n5283751526",
+     "1656", "195"},
+    {12, "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end", "10", "36"},
+    {13,
+     "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n"
+     "2,14\n8,10\n9,0\n\nfold along y=7\nfold along x=5",
+     "17", "#####\n#...#\n#...#\n#...#\n#####\n.....\n....."},
+    {14,
+     "NNCB\n\nCH -> B\nHH -> N\nCB -> H\nNH -> C\nHB -> C\nHC -> B\nHN -> C\nNN -> C\n"
+     "BH -> H\nNC -> B\nNB -> B\nBN -> B\nBB -> N\nBC -> B\nCC -> N\nCN -> C",
+     "1588", "2188189693529"},
+    {15,
+     "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n"
+     "3125421639\n1293138521\n2311944581",
+     "40", "315"},
+    {16, "8A004A801A8002F478", "16", "15"},
+    {17, "target area: x=20..30, y=-10..-5", "45", "112"},
+    {18,
+     "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]\n[[[5,[2,8]],4],[5,[[9,9],0]]]\n"
+     "[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]\n[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]\n"
+     "[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]\n[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]\n"
+     "[[[[5,4],[7,7]],8],[[8,3],8]]\n[[9,3],[[9,9],[6,[4,9]]]]\n"
+     "[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]\n[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+     "4140", "3993"},
+    {20,
+     "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###."
+     "..####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##.."
+     "#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#"
+     ".......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#"
+     ".####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#."
+     "......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#\n\n#..#.\n#....\n"
+     "##..#\n..#..\n..###",
+     "35", "3351"},
+    {21, "Player 1 starting position: 4\nPlayer 2 starting position: 8", "739785", ""},
+};
+
+static int expect_status(const char *name, AocStatus got, AocStatus want) {
+    if (got != want) {
+        fprintf(stderr, "%s: got status %d (%s), want %d\n", name, got, aoc_status_message(got),
+                want);
+        return 1;
+    }
+    return 0;
+}
+
+int main(void) {
+    int failures = 0;
+    AocAnswers answers;
+
+    for (size_t i = 0; i < sizeof(EXAMPLES) / sizeof(EXAMPLES[0]); i++) {
+        const struct example *ex = &EXAMPLES[i];
+        AocStatus status =
+            aoc_solve(ex->day, (const uint8_t *)ex->input, strlen(ex->input), &answers);
+
+        if (expect_status("aoc_solve", status, AOC_STATUS_OK)) {
+            failures++;
+            continue;
+        }
+        if (strcmp(answers.part_one, ex->part_one) != 0) {
+            fprintf(stderr, "day %d part one: got %s, want %s\n", ex->day, answers.part_one,
+                    ex->part_one);
+            failures++;
+        }
+        if (strcmp(answers.part_two, ex->part_two) != 0) {
+            fprintf(stderr, "day %d part two: got %s, want %s\n", ex->day, answers.part_two,
+                    ex->part_two);
+            failures++;
+        }
+    }
+
+    failures += expect_status("unknown day", aoc_solve(19, (const uint8_t *)"", 0, &answers),
+                              AOC_STATUS_UNKNOWN_DAY);
+    failures += expect_status("null input", aoc_solve(1, NULL, 0, &answers),
+                              AOC_STATUS_NULL_POINTER);
+    failures += expect_status("malformed input",
+                              aoc_solve(1, (const uint8_t *)"x", 1, &answers),
+                              AOC_STATUS_SOLVER_PANICKED);
+
+    if (failures == 0) {
+        printf("all examples passed\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
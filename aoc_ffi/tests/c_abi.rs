@@ -0,0 +1,55 @@
+//! Builds `tests/c/test_aoc.c` against the cdylib and runs it
+
+// The examples cover every day, so only run with all of them compiled in
+#![cfg(all(unix, feature = "all"))]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The cdylib is built next to the `deps` directory holding this test binary
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    deps.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn c_program_passes() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let lib_dir = library_dir();
+    let exe = Path::new(env!("CARGO_TARGET_TMPDIR")).join("test_aoc");
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+
+    let status = Command::new(cc)
+        .arg(manifest_dir.join("tests/c/test_aoc.c"))
+        .arg("-I")
+        .arg(env!("OUT_DIR"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc_ffi")
+        .arg("-o")
+        .arg(&exe)
+        .status()
+        .expect("Failed to run the C compiler");
+    assert!(status.success(), "compiling test_aoc.c failed");
+
+    let output = Command::new(&exe).output().unwrap();
+    assert!(
+        output.status.success(),
+        "test_aoc failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn committed_header_is_current() {
+    let generated = Path::new(env!("OUT_DIR")).join("aoc.h");
+    let committed = Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+    assert!(
+        fs::read_to_string(generated).unwrap() == fs::read_to_string(committed).unwrap(),
+        "include/aoc.h is out of date, rebuild with AOC_FFI_UPDATE_HEADER=1 to update it"
+    );
+}
@@ -0,0 +1,270 @@
+//! Stable C ABI for alternative solvers loaded at runtime, so that
+//! implementations built outside the repository can be compared with its own.
+//!
+//! A plugin is a shared library exporting three functions:
+//!
+//! ```c
+//! uint32_t aoc_plugin_abi_version(void);
+//! const char *aoc_plugin_name(void);
+//! uint32_t aoc_plugin_solve(uint8_t day, const uint8_t *input, size_t input_len,
+//!                           AocPluginAnswers *out);
+//! ```
+//!
+//! `aoc_plugin_solve` returns one of the `STATUS_` codes and only writes `out`
+//! on `STATUS_OK`. Rust plugins can use [`export_plugin!`] instead.
+
+use libloading::Library;
+use std::ffi::{c_char, CStr};
+use std::fmt;
+use std::panic;
+use std::path::Path;
+use std::slice;
+
+/// Bumped whenever the exported functions or `AocPluginAnswers` change
+pub const ABI_VERSION: u32 = 1;
+
+/// Size of each answer buffer, including the terminating nul byte
+pub const ANSWER_CAPACITY: usize = 512;
+
+pub const STATUS_OK: u32 = 0;
+/// The plugin has no solver for the day
+pub const STATUS_UNSUPPORTED_DAY: u32 = 1;
+/// The solver failed or panicked, or the input isn't UTF-8
+pub const STATUS_FAILED: u32 = 2;
+pub const STATUS_ANSWER_TOO_LONG: u32 = 3;
+
+/// Nul-terminated answers to both parts, part two empty if it isn't solved
+#[repr(C)]
+pub struct AocPluginAnswers {
+    pub part_one: [c_char; ANSWER_CAPACITY],
+    pub part_two: [c_char; ANSWER_CAPACITY],
+}
+
+/// The answers as text, part two only if it's solved
+pub type Answers = (String, Option<String>);
+
+/// A Rust plugin's solver, returning None for days it doesn't solve
+pub type Solve = fn(day: u8, input: &str) -> Option<Answers>;
+
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+type NameFn = unsafe extern "C" fn() -> *const c_char;
+type SolveFn = unsafe extern "C" fn(u8, *const u8, usize, *mut AocPluginAnswers) -> u32;
+
+/// Exports the plugin functions for `solve`, a [`Solve`] function,
+/// from a crate built as a `cdylib`
+#[macro_export]
+macro_rules! export_plugin {
+    ($name:literal, $solve:path) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_abi_version() -> u32 {
+            $crate::ABI_VERSION
+        }
+
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_name() -> *const ::std::ffi::c_char {
+            concat!($name, "\0").as_ptr().cast()
+        }
+
+        /// # Safety
+        ///
+        /// `input` must point to `input_len` readable bytes and `out` to a
+        /// writable `AocPluginAnswers`.
+        #[no_mangle]
+        pub unsafe extern "C" fn aoc_plugin_solve(
+            day: u8,
+            input: *const u8,
+            input_len: usize,
+            out: *mut $crate::AocPluginAnswers,
+        ) -> u32 {
+            $crate::solve_into(day, input, input_len, out, $solve)
+        }
+    };
+}
+
+/// Runs `solve` for `aoc_plugin_solve`, catching panics so they don't unwind into the host
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out` to a writable `AocPluginAnswers`.
+pub unsafe fn solve_into(
+    day: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut AocPluginAnswers,
+    solve: Solve,
+) -> u32 {
+    if input.is_null() || out.is_null() {
+        return STATUS_FAILED;
+    }
+    let Ok(input) = std::str::from_utf8(slice::from_raw_parts(input, input_len)) else {
+        return STATUS_FAILED;
+    };
+    let (part_one, part_two) = match panic::catch_unwind(|| solve(day, input)) {
+        Ok(Some(answers)) => answers,
+        Ok(None) => return STATUS_UNSUPPORTED_DAY,
+        Err(_) => return STATUS_FAILED,
+    };
+
+    let out = &mut *out;
+    if !copy_answer(&part_one, &mut out.part_one)
+        || !copy_answer(part_two.as_deref().unwrap_or(""), &mut out.part_two)
+    {
+        return STATUS_ANSWER_TOO_LONG;
+    }
+    STATUS_OK
+}
+
+/// Copies `answer` into `buf` with a terminating nul, returns false if it doesn't fit
+fn copy_answer(answer: &str, buf: &mut [c_char; ANSWER_CAPACITY]) -> bool {
+    if answer.len() >= ANSWER_CAPACITY {
+        return false;
+    }
+    for (dst, &src) in buf.iter_mut().zip(answer.as_bytes()) {
+        *dst = src as c_char;
+    }
+    buf[answer.len()] = 0;
+    true
+}
+
+/// Why a plugin couldn't be loaded or didn't answer
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Load(String),
+    /// The plugin was built against another version of the ABI
+    AbiVersion(u32),
+    UnsupportedDay(u8),
+    Failed,
+    AnswerTooLong,
+    /// The plugin returned a status code this ABI version doesn't define
+    UnknownStatus(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Load(reason) => write!(f, "failed to load plugin: {}", reason),
+            Error::AbiVersion(version) => write!(
+                f,
+                "plugin uses ABI version {}, expected {}",
+                version, ABI_VERSION
+            ),
+            Error::UnsupportedDay(day) => write!(f, "plugin doesn't solve day {}", day),
+            Error::Failed => write!(f, "plugin failed to solve the input"),
+            Error::AnswerTooLong => write!(f, "answer doesn't fit in the plugin's buffer"),
+            Error::UnknownStatus(status) => write!(f, "plugin returned unknown status {}", status),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A loaded plugin library
+pub struct Plugin {
+    name: String,
+    solve: SolveFn,
+    // Keeps `solve` valid, so it's dropped last
+    _library: Library,
+}
+
+impl Plugin {
+    /// Loads the plugin at `path`, checking its ABI version.
+    /// Loading runs the library's initialisers, so only load plugins you trust.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let load = |err: libloading::Error| Error::Load(err.to_string());
+        unsafe {
+            let library = Library::new(path.as_ref()).map_err(load)?;
+            let version = *library
+                .get::<AbiVersionFn>(b"aoc_plugin_abi_version\0")
+                .map_err(load)?;
+            if version() != ABI_VERSION {
+                return Err(Error::AbiVersion(version()));
+            }
+            let name = *library.get::<NameFn>(b"aoc_plugin_name\0").map_err(load)?;
+            let name = CStr::from_ptr(name()).to_string_lossy().into_owned();
+            let solve = *library
+                .get::<SolveFn>(b"aoc_plugin_solve\0")
+                .map_err(load)?;
+            Ok(Plugin {
+                name,
+                solve,
+                _library: library,
+            })
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn solve(&self, day: u8, input: &str) -> Result<Answers, Error> {
+        let mut out = AocPluginAnswers {
+            part_one: [0; ANSWER_CAPACITY],
+            part_two: [0; ANSWER_CAPACITY],
+        };
+        let status = unsafe { (self.solve)(day, input.as_ptr(), input.len(), &mut out) };
+        match status {
+            STATUS_OK => {}
+            STATUS_UNSUPPORTED_DAY => return Err(Error::UnsupportedDay(day)),
+            STATUS_FAILED => return Err(Error::Failed),
+            STATUS_ANSWER_TOO_LONG => return Err(Error::AnswerTooLong),
+            status => return Err(Error::UnknownStatus(status)),
+        }
+
+        // Nul-terminate defensively, in case the plugin filled the whole buffer
+        let text = |buf: &mut [c_char; ANSWER_CAPACITY]| {
+            buf[ANSWER_CAPACITY - 1] = 0;
+            unsafe { CStr::from_ptr(buf.as_ptr()) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        let part_one = text(&mut out.part_one);
+        let part_two = Some(text(&mut out.part_two)).filter(|answer| !answer.is_empty());
+        Ok((part_one, part_two))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(day: u8, input: &str) -> Option<Answers> {
+        match day {
+            1 => Some((input.len().to_string(), None)),
+            2 => panic!("day 2 is broken"),
+            3 => Some(("x".repeat(ANSWER_CAPACITY), None)),
+            _ => None,
+        }
+    }
+
+    fn call(day: u8, input: &str) -> (u32, AocPluginAnswers) {
+        let mut out = AocPluginAnswers {
+            part_one: [0; ANSWER_CAPACITY],
+            part_two: [0; ANSWER_CAPACITY],
+        };
+        let status = unsafe { solve_into(day, input.as_ptr(), input.len(), &mut out, solve) };
+        (status, out)
+    }
+
+    #[test]
+    fn writes_answers() {
+        let (status, out) = call(1, "abc");
+        assert_eq!(status, STATUS_OK);
+        assert_eq!(&out.part_one[..2], [b'3' as c_char, 0]);
+        assert_eq!(out.part_two[0], 0);
+    }
+
+    #[test]
+    fn reports_failures() {
+        assert_eq!(call(2, "").0, STATUS_FAILED);
+        assert_eq!(call(3, "").0, STATUS_ANSWER_TOO_LONG);
+        assert_eq!(call(4, "").0, STATUS_UNSUPPORTED_DAY);
+    }
+
+    #[test]
+    fn missing_library() {
+        assert!(matches!(
+            Plugin::load("no_such_plugin.so"),
+            Err(Error::Load(_))
+        ));
+    }
+}
@@ -0,0 +1,45 @@
+//! A sample plugin with its own solutions to days 1 and 6, used to test
+//! loading plugins and as a starting point for new ones.
+//!
+//! Build it with `cargo build -p aoc_plugin_sample` and compare it with the
+//! built-in solvers with `aoc compare 1 --plugin target/debug/libaoc_plugin_sample.so`.
+
+use aoc_plugin::Answers;
+
+aoc_plugin::export_plugin!("sample", solve);
+
+pub fn solve(day: u8, input: &str) -> Option<Answers> {
+    match day {
+        1 => Some(sonar_sweep(input)),
+        6 => Some(lanternfish(input)),
+        _ => None,
+    }
+}
+
+/// Sums each sliding window rather than comparing the numbers leaving and entering it
+fn sonar_sweep(input: &str) -> Answers {
+    let depths: Vec<u32> = input.lines().map(|line| line.parse().unwrap()).collect();
+    let increases = |window: usize| {
+        let sums: Vec<u32> = depths.windows(window).map(|w| w.iter().sum()).collect();
+        sums.windows(2).filter(|pair| pair[1] > pair[0]).count()
+    };
+    (increases(1).to_string(), Some(increases(3).to_string()))
+}
+
+/// Rotates the counts of fish per timer, adding the fish that reset back in
+fn lanternfish(input: &str) -> Answers {
+    let mut timers = [0u64; 9];
+    for timer in input.trim().split(',') {
+        timers[timer.parse::<usize>().unwrap()] += 1;
+    }
+    let mut population = |days| {
+        for _ in 0..days {
+            timers.rotate_left(1);
+            timers[6] += timers[8];
+        }
+        timers.iter().sum::<u64>()
+    };
+    let after_80 = population(80);
+    let after_256 = population(256 - 80);
+    (after_80.to_string(), Some(after_256.to_string()))
+}
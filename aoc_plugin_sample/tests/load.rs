@@ -0,0 +1,48 @@
+//! Loads the sample plugin's cdylib and checks it against the built-in solvers
+
+use aoc_plugin::{Error, Plugin};
+use std::env;
+use std::path::PathBuf;
+
+/// The cdylib built for this test run sits in `deps` next to this test
+/// binary, and is only copied up a directory by a separate `cargo build`
+fn library_path() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    let name = format!(
+        "{}aoc_plugin_sample{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    );
+    [deps, deps.parent().unwrap()]
+        .iter()
+        .map(|dir| dir.join(&name))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| deps.join(&name))
+}
+
+fn built_in(day: u8, input: &str) -> (String, Option<String>) {
+    let (part_one, part_two) = aoc::solve(day, input).unwrap();
+    (
+        part_one.to_string(),
+        part_two.map(|answer| answer.to_string()),
+    )
+}
+
+#[test]
+fn matches_built_in_solvers() {
+    let plugin = Plugin::load(library_path()).unwrap();
+    assert_eq!(plugin.name(), "sample");
+
+    let sonar = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+    assert_eq!(plugin.solve(1, sonar), Ok(built_in(1, sonar)));
+    let lanternfish = "3,4,3,1,2";
+    assert_eq!(plugin.solve(6, lanternfish), Ok(built_in(6, lanternfish)));
+}
+
+#[test]
+fn unsupported_days_and_failures() {
+    let plugin = Plugin::load(library_path()).unwrap();
+    assert_eq!(plugin.solve(2, "forward 5"), Err(Error::UnsupportedDay(2)));
+    assert_eq!(plugin.solve(1, "deep"), Err(Error::Failed));
+}
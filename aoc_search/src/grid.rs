@@ -0,0 +1,32 @@
+/// The up to four positions next to `(x, y)` in a `width` by `height` grid
+pub fn orthogonal(
+    (x, y): (usize, usize),
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    [
+        (x > 0).then(|| (x - 1, y)),
+        (y > 0).then(|| (x, y - 1)),
+        (x + 1 < width).then(|| (x + 1, y)),
+        (y + 1 < height).then(|| (x, y + 1)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corners_and_middle() {
+        assert_eq!(
+            orthogonal((0, 0), 3, 2).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            orthogonal((1, 1), 3, 3).collect::<Vec<_>>(),
+            [(0, 1), (1, 0), (2, 1), (1, 2)]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Generic graph search shared by the days.
+//!
+//! Every search takes the start node and caller-supplied functions giving the
+//! neighbours of a node (with the cost of each step for the weighted searches)
+//! and whether a node is the goal. Types implementing [`Graph`] get the same
+//! searches as methods.
+
+mod grid;
+mod hash;
+mod path;
+mod unweighted;
+mod weighted;
+
+pub use grid::orthogonal;
+pub use hash::{FxHasher, NodeMap, NodeSet};
+pub use path::{reconstruct, reconstruct_with};
+pub use unweighted::{bfs, count_paths, dfs};
+pub use weighted::{astar, dial, dijkstra, Cost};
+
+use std::hash::Hash;
+
+/// A graph with weighted edges, searched through its provided methods
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+    type Cost: Cost;
+
+    /// The nodes one step away from `node`, with the cost of each step
+    fn edges<'a>(
+        &'a self,
+        node: &Self::Node,
+    ) -> impl IntoIterator<Item = (Self::Node, Self::Cost)> + use<'a, Self>;
+
+    /// The nodes one step away from `node`, ignoring the costs
+    fn neighbours<'a>(
+        &'a self,
+        node: &Self::Node,
+    ) -> impl IntoIterator<Item = Self::Node> + use<'a, Self> {
+        self.edges(node).into_iter().map(|(next, _)| next)
+    }
+
+    /// Path with the fewest steps from `start` to a goal, see [`bfs`]
+    fn bfs(
+        &self,
+        start: Self::Node,
+        is_goal: impl FnMut(&Self::Node) -> bool,
+    ) -> Option<Vec<Self::Node>> {
+        bfs(start, |node| self.neighbours(node), is_goal)
+    }
+
+    /// Any path from `start` to a goal, see [`dfs`]
+    fn dfs(
+        &self,
+        start: Self::Node,
+        is_goal: impl FnMut(&Self::Node) -> bool,
+    ) -> Option<Vec<Self::Node>> {
+        dfs(start, |node| self.neighbours(node), is_goal)
+    }
+
+    /// Cheapest path from `start` to a goal and its cost, see [`dijkstra`]
+    fn dijkstra(
+        &self,
+        start: Self::Node,
+        is_goal: impl FnMut(&Self::Node) -> bool,
+    ) -> Option<(Vec<Self::Node>, Self::Cost)> {
+        dijkstra(start, |node| self.edges(node), is_goal)
+    }
+
+    /// Cheapest path from `start` to a goal and its cost, see [`astar`]
+    fn astar(
+        &self,
+        start: Self::Node,
+        heuristic: impl FnMut(&Self::Node) -> Self::Cost,
+        is_goal: impl FnMut(&Self::Node) -> bool,
+    ) -> Option<(Vec<Self::Node>, Self::Cost)> {
+        astar(start, |node| self.edges(node), heuristic, is_goal)
+    }
+
+    /// Cost of the cheapest path from `start` to a goal, for graphs whose
+    /// `nodes` nodes `index` numbers and whose steps cost at most `max_step`,
+    /// see [`dial`]
+    fn dial(
+        &self,
+        start: Self::Node,
+        nodes: usize,
+        index: impl Fn(&Self::Node) -> usize,
+        max_step: Self::Cost,
+        is_goal: impl FnMut(&Self::Node) -> bool,
+    ) -> Option<Self::Cost>
+    where
+        Self::Cost: Into<usize>,
+    {
+        dial(
+            start,
+            nodes,
+            index,
+            max_step,
+            |node| self.edges(node),
+            is_goal,
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::NodeMap;
+use std::hash::Hash;
+
+/// Follows the `parents` links back from `end` to the node without a parent,
+/// returning the path in order from that node to `end`
+pub fn reconstruct<N: Clone + Eq + Hash>(parents: &NodeMap<N, N>, end: N) -> Vec<N> {
+    reconstruct_with(end, |node| parents.get(node).cloned())
+}
+
+/// Like [`reconstruct`], looking up the parent of each node with `parent`
+pub fn reconstruct_with<N>(end: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut path = vec![end];
+    while let Some(next) = parent(&path[path.len() - 1]) {
+        path.push(next);
+    }
+    path.reverse();
+    path
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_parents() {
+        let parents = NodeMap::from_iter([(2, 1), (3, 2), (1, 0)]);
+        assert_eq!(reconstruct(&parents, 3), vec![0, 1, 2, 3]);
+        assert_eq!(reconstruct(&parents, 0), vec![0]);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::path::reconstruct;
+use crate::{NodeMap, NodeSet};
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Breadth first search, returns a path with the fewest steps from `start` to
+/// the first node satisfying `is_goal`, including both ends
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = NodeMap::default();
+    let mut seen = NodeSet::from_iter([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Depth first search, returns the first path found from `start` to a node
+/// satisfying `is_goal`, including both ends. Visits every node at most once.
+pub fn dfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = NodeMap::default();
+    let mut seen = NodeSet::default();
+    let mut stack = vec![(start, None)];
+
+    while let Some((node, parent)) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        if let Some(parent) = parent {
+            parents.insert(node.clone(), parent);
+        }
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node));
+        }
+        for next in neighbours(&node) {
+            if !seen.contains(&next) {
+                stack.push((next, Some(node.clone())));
+            }
+        }
+    }
+    None
+}
+
+/// Counts every distinct path from `start` to a node satisfying `is_goal`,
+/// without continuing past goals.
+///
+/// Nodes are not marked as visited, so `neighbours` must make the search
+/// finite, typically by carrying what was visited so far in the node itself.
+pub fn count_paths<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    I: IntoIterator<Item = N>,
+{
+    let mut count = 0;
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if is_goal(&node) {
+            count += 1;
+        } else {
+            stack.extend(neighbours(&node));
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 -> 1 -> 2 -> 3 and a shortcut 0 -> 3, on a cycle back to 0
+    fn successors(node: &u8) -> Vec<u8> {
+        match node {
+            0 => vec![1, 3],
+            1 => vec![2],
+            2 => vec![3],
+            3 => vec![0],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        assert_eq!(bfs(0, successors, |&n| n == 3), Some(vec![0, 3]));
+        assert_eq!(bfs(1, successors, |&n| n == 0), Some(vec![1, 2, 3, 0]));
+        assert_eq!(bfs(0, successors, |&n| n == 4), None);
+    }
+
+    #[test]
+    fn dfs_terminates_on_cycles() {
+        let path = dfs(1, successors, |&n| n == 0).unwrap();
+        assert_eq!(path, vec![1, 2, 3, 0]);
+        assert_eq!(dfs(0, successors, |&n| n == 4), None);
+    }
+
+    #[test]
+    fn count_paths_in_grid() {
+        // Moving only right or down through a 3x3 grid of corners
+        let moves = |&(x, y): &(u8, u8)| {
+            [(x < 2).then(|| (x + 1, y)), (y < 2).then(|| (x, y + 1))]
+                .into_iter()
+                .flatten()
+        };
+        assert_eq!(count_paths((0, 0), moves, |&p| p == (2, 2)), 6);
+    }
+}
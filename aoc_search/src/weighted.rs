@@ -0,0 +1,206 @@
+use crate::path::reconstruct_with;
+use crate::NodeMap;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry as Known;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge and path costs. `Default` must be the zero cost.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// A node waiting in the fringe, ordered so the heap pops the lowest estimate
+/// first and, among equal estimates, the one furthest along its path
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(self.cost.cmp(&other.cost))
+    }
+}
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+/// Returns the cheapest path from `start` to a node satisfying `is_goal` and its
+/// cost. `edges` gives the nodes one step away with the cost of each step.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    edges: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, edges, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], visiting nodes in order of their cost plus `heuristic`.
+/// The heuristic must never overestimate the remaining cost to a goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut edges: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The cheapest known cost of reaching each node and the node it was reached from
+    let mut best: NodeMap<N, (C, Option<N>)> =
+        NodeMap::from_iter([(start.clone(), (C::default(), None))]);
+    let mut fringe = BinaryHeap::from([Entry {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    }]);
+
+    while let Some(Entry { cost, node, .. }) = fringe.pop() {
+        if is_goal(&node) {
+            let path = reconstruct_with(node, |node| best[node].1.clone());
+            return Some((path, cost));
+        }
+        // Skip entries superseded by a cheaper path found after they were pushed
+        if cost > best[&node].0 {
+            continue;
+        }
+
+        for (next, step) in edges(&node) {
+            let cost = cost + step;
+            let improved = match best.entry(next.clone()) {
+                Known::Occupied(mut known) if cost < known.get().0 => {
+                    known.insert((cost, Some(node.clone())));
+                    true
+                }
+                Known::Occupied(_) => false,
+                Known::Vacant(unknown) => {
+                    unknown.insert((cost, Some(node.clone())));
+                    true
+                }
+            };
+            if improved {
+                fringe.push(Entry {
+                    estimate: cost + heuristic(&next),
+                    cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// The cost of the cheapest path from `start` to a goal, for nodes that
+/// `index` numbers from 0 up to `nodes` and steps costing at most `max_step`.
+/// Dial's algorithm: the costs live in an array indexed by node and the
+/// fringe in a ring of buckets, one per cost, so nothing is hashed or heaped.
+/// Unlike [`dijkstra`] it doesn't keep the path, which would take longer than
+/// the search on large grids, as the parents don't fit in the cache.
+pub fn dial<N, C, I>(
+    start: N,
+    nodes: usize,
+    index: impl Fn(&N) -> usize,
+    max_step: C,
+    mut edges: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<C>
+where
+    C: Cost + Into<usize>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // The cheapest known cost of reaching each node
+    let mut best: Vec<Option<C>> = vec![None; nodes];
+    best[index(&start)] = Some(C::default());
+    // Every waiting node costs at most `max_step` more than the cheapest,
+    // so the bucket of a cost is only reused once the cost is done with
+    let ring = max_step.into() + 1;
+    let mut buckets: Vec<Vec<(C, N)>> = (0..ring).map(|_| Vec::new()).collect();
+    buckets[0].push((C::default(), start));
+    let mut waiting = 1;
+    let mut bucket = 0;
+
+    while waiting > 0 {
+        let Some((cost, node)) = buckets[bucket].pop() else {
+            bucket = (bucket + 1) % ring;
+            continue;
+        };
+        waiting -= 1;
+        // Skip entries superseded by a cheaper path found after they were pushed
+        if best[index(&node)].is_some_and(|known| cost > known) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(cost);
+        }
+
+        for (next, step) in edges(&node) {
+            debug_assert!(step <= max_step, "step costs more than max_step");
+            let cost = cost + step;
+            let i = index(&next);
+            if best[i].is_none_or(|known| cost < known) {
+                best[i] = Some(cost);
+                buckets[cost.into() % ring].push((cost, next));
+                waiting += 1;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The direct edge 0 -> 3 costs more than going round through 1 and 2
+    fn edges(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_finds_cheapest() {
+        assert_eq!(dijkstra(0, edges, |&n| n == 3), Some((vec![0, 1, 2, 3], 6)));
+        assert_eq!(dijkstra(3, edges, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn dial_matches_dijkstra() {
+        let index = |&n: &u8| n as usize;
+        let edges = |n: &u8| edges(n).into_iter().map(|(n, c)| (n, c as u8));
+        assert_eq!(dial(0, 4, index, 10, edges, |&n| n == 3), Some(6));
+        assert_eq!(dial(3, 4, index, 10, edges, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let remaining = |&n: &u8| 3 - n as u32;
+        assert_eq!(
+            astar(0, edges, remaining, |&n| n == 3),
+            Some((vec![0, 1, 2, 3], 6))
+        );
+    }
+}
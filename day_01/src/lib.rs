@@ -0,0 +1,197 @@
+pub mod report;
+
+pub use report::SonarReport;
+
+use aoc_common::{Answers, Error};
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        count_increases(&parsed_input).into(),
+        Some(count_increases_triplets(&parsed_input).into()),
+    ))
+}
+
+pub fn parse_input(input: &str) -> Vec<u32> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn count_increases(input: &[u32]) -> u32 {
+    count_window_increases(input.iter().copied(), 1) as u32
+}
+
+pub fn count_increases_triplets(input: &[u32]) -> u32 {
+    count_window_increases(input.iter().copied(), 3) as u32
+}
+
+/// Reads one reading per line, without holding more than a line in memory
+pub fn readings(reader: impl BufRead) -> impl Iterator<Item = io::Result<u32>> {
+    reader.lines().map(|line| {
+        let line = line?;
+        line.trim().parse().map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid reading {:?}", line),
+            )
+        })
+    })
+}
+
+/// What is compared between consecutive windows
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Statistic {
+    #[default]
+    Sum,
+    Mean,
+    /// The mean of the two middle readings for even window sizes
+    Median,
+}
+
+/// Counts how often the sum of `n` consecutive readings is larger than the
+/// previous window's, keeping only the current window in memory
+pub fn count_window_increases(readings: impl IntoIterator<Item = u32>, n: usize) -> u64 {
+    count_window_increases_by(readings, n, Statistic::Sum)
+}
+
+/// Counts how often the statistic of `n` consecutive readings is larger than
+/// the previous window's, keeping only the current window in memory
+pub fn count_window_increases_by(
+    readings: impl IntoIterator<Item = u32>,
+    n: usize,
+    statistic: Statistic,
+) -> u64 {
+    assert!(n > 0, "windows must hold at least one reading");
+    let mut window = VecDeque::with_capacity(n);
+    // The window's readings in order, only kept for medians
+    let mut sorted = Vec::with_capacity(n);
+    let mut previous_median = None;
+    let mut counter = 0;
+
+    for reading in readings {
+        if window.len() < n {
+            window.push_back(reading);
+            if statistic == Statistic::Median {
+                insert_sorted(&mut sorted, reading);
+                if window.len() == n {
+                    previous_median = Some(double_median(&sorted));
+                }
+            }
+            continue;
+        }
+
+        let leaving = window.pop_front().unwrap();
+        window.push_back(reading);
+        let increased = match statistic {
+            // Consecutive windows share all but one reading, and have the
+            // same size, so only the readings entering and leaving matter
+            Statistic::Sum | Statistic::Mean => reading > leaving,
+            Statistic::Median => {
+                let index = sorted.binary_search(&leaving).unwrap();
+                sorted.remove(index);
+                insert_sorted(&mut sorted, reading);
+                let median = double_median(&sorted);
+                previous_median.replace(median) < Some(median)
+            }
+        };
+        if increased {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+fn insert_sorted(sorted: &mut Vec<u32>, reading: u32) {
+    let index = sorted.partition_point(|&r| r < reading);
+    sorted.insert(index, reading);
+}
+
+/// Twice the median, which is a whole number even when the median isn't
+fn double_median(sorted: &[u32]) -> u64 {
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        2 * u64::from(sorted[middle])
+    } else {
+        u64::from(sorted[middle - 1]) + u64::from(sorted[middle])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(count_increases(&parse_input(EXAMPLE)), 7);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(count_increases_triplets(&parse_input(EXAMPLE)), 5);
+    }
+
+    #[test]
+    fn window_sizes() {
+        let readings = || parse_input(EXAMPLE).into_iter();
+        assert_eq!(count_window_increases(readings(), 1), 7);
+        assert_eq!(count_window_increases(readings(), 3), 5);
+        assert_eq!(count_window_increases(readings(), 10), 0);
+        assert_eq!(count_window_increases(readings(), 11), 0);
+    }
+
+    #[test]
+    fn means_match_sums() {
+        for n in 1..=5 {
+            let readings = || parse_input(EXAMPLE).into_iter();
+            assert_eq!(
+                count_window_increases_by(readings(), n, Statistic::Mean),
+                count_window_increases(readings(), n)
+            );
+        }
+    }
+
+    #[test]
+    fn medians() {
+        // Windows of 3: medians 200, 208, 208, 207, 207, 240, 260, 263
+        let readings = parse_input(EXAMPLE).into_iter();
+        assert_eq!(count_window_increases_by(readings, 3, Statistic::Median), 4);
+        // Even windows average the middle two: 204, 209, 205, 203.5, 223.5, 219.5
+        let readings = [200, 208, 210, 200, 207, 240, 199];
+        assert_eq!(count_window_increases_by(readings, 2, Statistic::Median), 2);
+        // A single reading is its own median
+        let readings = parse_input(EXAMPLE).into_iter();
+        assert_eq!(count_window_increases_by(readings, 1, Statistic::Median), 7);
+    }
+
+    #[test]
+    fn streams_readings() {
+        let readings: Vec<u32> = readings(EXAMPLE.as_bytes()).map(Result::unwrap).collect();
+        assert_eq!(readings, parse_input(EXAMPLE));
+        assert!(super::readings("1\nten\n".as_bytes())
+            .nth(1)
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn long_inputs() {
+        // Far more readings than could be collected, generated lazily
+        let readings = (0..10_000_000u32).map(|i| i % 1000);
+        assert_eq!(count_window_increases(readings, 500), 5_000_000);
+    }
+}
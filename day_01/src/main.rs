@@ -1,7 +1,70 @@
-use std::fs;
+use day_01::{
+    count_increases, count_increases_triplets, count_window_increases_by, parse_input, readings,
+    SonarReport, Statistic,
+};
 use std::time::Instant;
+use std::{env, fs, io, process};
+
+const USAGE: &str = "\
+Usage: day_01 [--window n [--sum | --mean | --median]]
+       day_01 --report [--bucket-width n] [--json]
+  Without options, solves input.txt. With --window, counts the windows of n
+  readings from stdin whose sum, mean or median increased. With --report,
+  summarises the depth profile of input.txt, in buckets 100 deep by default.";
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        solve_input();
+        return;
+    }
+
+    let mut window = None;
+    let mut statistic = Statistic::Sum;
+    let mut report = false;
+    let mut bucket_width = 100;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--report" => report = true,
+            "--bucket-width" => {
+                bucket_width = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .unwrap_or_else(|| exit(USAGE))
+            }
+            "--json" => json = true,
+            "--window" => window = args.next().and_then(|n| n.parse().ok()).filter(|&n| n > 0),
+            "--sum" => statistic = Statistic::Sum,
+            "--mean" => statistic = Statistic::Mean,
+            "--median" => statistic = Statistic::Median,
+            _ => exit(USAGE),
+        }
+    }
+    if report {
+        let contents =
+            fs::read_to_string("input.txt").expect("Something went wrong reading the file");
+        let report = SonarReport::new(&parse_input(&contents), bucket_width);
+        if json {
+            println!("{}", report.to_json());
+        } else {
+            print!("{}", report);
+        }
+        return;
+    }
+    let Some(window) = window else {
+        exit(USAGE);
+    };
+
+    let readings = readings(io::stdin().lock()).map(|reading| {
+        reading.unwrap_or_else(|err| exit(&format!("Failed to read stdin: {}", err)))
+    });
+    println!("{}", count_window_increases_by(readings, window, statistic));
+}
+
+fn solve_input() {
     let contents = fs::read_to_string("input.txt").expect("Something went wrong reading the file");
 
     let now = Instant::now();
@@ -17,30 +80,7 @@ fn main() {
     );
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input.lines().map(|line| line.parse().unwrap()).collect()
-}
-
-fn count_increases(input: &[u32]) -> u32 {
-    let mut counter = 0;
-
-    for i in 1..input.len() {
-        if input[i] > input[i - 1] {
-            counter += 1;
-        }
-    }
-    counter
-}
-
-/// The last two numbers of the first triple and the first two of the next triplet overlap
-/// Only compare the non-overlapping numbers
-fn count_increases_triplets(input: &[u32]) -> u32 {
-    let mut counter = 0;
-
-    for i in 3..input.len() {
-        if input[i] > input[i - 3] {
-            counter += 1;
-        }
-    }
-    counter
+fn exit(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
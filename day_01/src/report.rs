@@ -0,0 +1,253 @@
+//! A summary of a sonar sweep's depth profile, for reading rather than answering the puzzle
+
+use serde::Serialize;
+use std::fmt;
+
+/// Consecutive readings, each deeper than the one before
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Run {
+    /// Index of the first reading
+    pub start: usize,
+    /// Number of readings, so one more than the number of increases
+    pub length: usize,
+}
+
+/// The change between a reading and the one before it
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct DepthDrop {
+    /// Index of the shallower reading
+    pub index: usize,
+    pub from: u32,
+    pub to: u32,
+}
+
+/// The readings in `start..end`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Bucket {
+    pub start: u32,
+    pub end: u32,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SonarReport {
+    pub readings: usize,
+    /// The first of the longest runs, None without readings
+    pub longest_increase: Option<Run>,
+    /// The first of the largest drops, None if the sweep never gets shallower
+    pub largest_drop: Option<DepthDrop>,
+    /// Buckets of equal width covering every reading, including empty ones
+    pub histogram: Vec<Bucket>,
+    /// Indices of readings deeper than the readings on either side. A plateau
+    /// deeper than both of its sides counts once, at its first reading.
+    pub local_maxima: Vec<usize>,
+}
+
+impl SonarReport {
+    /// Summarises the readings from `parse_input`, with histogram buckets `bucket_width` deep
+    pub fn new(depths: &[u32], bucket_width: u32) -> Self {
+        assert!(bucket_width > 0, "buckets must be at least 1 deep");
+        SonarReport {
+            readings: depths.len(),
+            longest_increase: longest_increase(depths),
+            largest_drop: largest_drop(depths),
+            histogram: histogram(depths, bucket_width),
+            local_maxima: local_maxima(depths),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+fn longest_increase(depths: &[u32]) -> Option<Run> {
+    let mut longest: Option<Run> = None;
+    let mut start = 0;
+    for i in 1..=depths.len() {
+        if i == depths.len() || depths[i] <= depths[i - 1] {
+            let length = i - start;
+            if longest.is_none_or(|run| length > run.length) {
+                longest = Some(Run { start, length });
+            }
+            start = i;
+        }
+    }
+    longest
+}
+
+fn largest_drop(depths: &[u32]) -> Option<DepthDrop> {
+    let mut largest: Option<DepthDrop> = None;
+    for (i, pair) in depths.windows(2).enumerate() {
+        let (from, to) = (pair[0], pair[1]);
+        if to < from && largest.is_none_or(|drop| from - to > drop.from - drop.to) {
+            largest = Some(DepthDrop {
+                index: i + 1,
+                from,
+                to,
+            });
+        }
+    }
+    largest
+}
+
+fn histogram(depths: &[u32], width: u32) -> Vec<Bucket> {
+    let (Some(&min), Some(&max)) = (depths.iter().min(), depths.iter().max()) else {
+        return Vec::new();
+    };
+    let first = min / width;
+    let mut histogram: Vec<Bucket> = (first..=max / width)
+        .map(|bucket| Bucket {
+            start: bucket * width,
+            end: bucket.saturating_mul(width).saturating_add(width),
+            count: 0,
+        })
+        .collect();
+    for &depth in depths {
+        histogram[(depth / width - first) as usize].count += 1;
+    }
+    histogram
+}
+
+fn local_maxima(depths: &[u32]) -> Vec<usize> {
+    let mut maxima = Vec::new();
+    let mut i = 1;
+    while i + 1 < depths.len() {
+        if depths[i] <= depths[i - 1] {
+            i += 1;
+            continue;
+        }
+        // Skip to the end of a plateau to see which way it goes
+        let mut end = i;
+        while end + 1 < depths.len() && depths[end + 1] == depths[i] {
+            end += 1;
+        }
+        if end + 1 < depths.len() && depths[end + 1] < depths[i] {
+            maxima.push(i);
+        }
+        i = end + 1;
+    }
+    maxima
+}
+
+impl fmt::Display for SonarReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Readings: {}", self.readings)?;
+        match self.longest_increase {
+            Some(run) => writeln!(
+                f,
+                "Longest increase: {} readings from index {}",
+                run.length, run.start
+            )?,
+            None => writeln!(f, "Longest increase: none")?,
+        }
+        match self.largest_drop {
+            Some(drop) => writeln!(
+                f,
+                "Largest drop: {} at index {} ({} to {})",
+                drop.from - drop.to,
+                drop.index,
+                drop.from,
+                drop.to
+            )?,
+            None => writeln!(f, "Largest drop: none")?,
+        }
+        let maxima: Vec<String> = self.local_maxima.iter().map(|i| i.to_string()).collect();
+        writeln!(f, "Local maxima: {}", maxima.join(", "))?;
+        writeln!(f, "Histogram:")?;
+        let width = self
+            .histogram
+            .last()
+            .map_or(0, |bucket| bucket.end.to_string().len());
+        for bucket in &self.histogram {
+            writeln!(
+                f,
+                "  {:>width$}..{:<width$} {:>6}",
+                bucket.start, bucket.end, bucket.count
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    const EXAMPLE: &str = "\
+199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn example() {
+        let report = SonarReport::new(&parse_input(EXAMPLE), 20);
+        assert_eq!(report.readings, 10);
+        assert_eq!(
+            report.longest_increase,
+            Some(Run {
+                start: 0,
+                length: 4
+            })
+        );
+        assert_eq!(
+            report.largest_drop,
+            Some(DepthDrop {
+                index: 4,
+                from: 210,
+                to: 200
+            })
+        );
+        let counts: Vec<(u32, usize)> = report
+            .histogram
+            .iter()
+            .map(|bucket| (bucket.start, bucket.count))
+            .collect();
+        assert_eq!(counts, [(180, 1), (200, 5), (220, 0), (240, 1), (260, 3)]);
+        assert_eq!(report.local_maxima, [3, 7]);
+    }
+
+    #[test]
+    fn edge_cases() {
+        let empty = SonarReport::new(&[], 10);
+        assert_eq!(empty.longest_increase, None);
+        assert!(empty.histogram.is_empty());
+
+        let report = SonarReport::new(&[1, 2, 3], 10);
+        assert_eq!(report.largest_drop, None);
+        assert!(report.local_maxima.is_empty());
+
+        // Plateaus count once, and only if both sides are shallower
+        let report = SonarReport::new(&[1, 5, 5, 2, 3, 3, 4, 4], 10);
+        assert_eq!(report.local_maxima, [1]);
+    }
+
+    #[test]
+    fn text_and_json() {
+        let report = SonarReport::new(&[5, 7, 3], 5);
+        assert_eq!(
+            report.to_string(),
+            "\
+Readings: 3
+Longest increase: 2 readings from index 0
+Largest drop: 4 at index 2 (7 to 3)
+Local maxima: 1
+Histogram:
+   0..5       1
+   5..10      2
+"
+        );
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["largest_drop"]["index"], 2);
+        assert_eq!(json["histogram"][1]["count"], 2);
+        assert_eq!(json["local_maxima"], serde_json::json!([1]));
+    }
+}
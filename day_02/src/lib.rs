@@ -0,0 +1,503 @@
+pub mod plan;
+pub mod script;
+pub mod trajectory;
+
+use aoc_common::{Answers, Error, Violation};
+use std::borrow::Borrow;
+use std::fmt;
+use std::io::{self, BufRead};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input).map_err(Error::InvalidInput)?;
+
+    let overflow = |err: CourseError| Error::Overflow(err.to_string());
+
+    Ok((
+        final_position(&parsed_input).map_err(overflow)?.into(),
+        Some(
+            final_position_with_aim(&parsed_input)
+                .map_err(overflow)?
+                .into(),
+        ),
+    ))
+}
+
+/// Parses the whole course, stopping at the first invalid line
+pub fn parse_input(contents: &str) -> Result<Vec<Direction>, Violation> {
+    parse_commands(contents.lines()).collect()
+}
+
+/// Parses one command per line as they're needed, skipping blank lines and `#` comments
+pub fn parse_commands<I>(lines: I) -> impl Iterator<Item = Result<Direction, Violation>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, line)| parse_line(index, line.as_ref()).transpose())
+}
+
+/// Reads and parses one command per line, without holding more than a line in memory
+pub fn read_commands(reader: impl BufRead) -> impl Iterator<Item = io::Result<Direction>> {
+    let mut index = 0;
+    reader.lines().filter_map(move |line| {
+        index += 1;
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => return Some(Err(err)),
+        };
+        parse_line(index - 1, &line)
+            .map_err(|violation| io::Error::new(io::ErrorKind::InvalidData, violation.to_string()))
+            .transpose()
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Forward(u32),
+    Down(u32),
+    Up(u32),
+}
+
+/// Parses the command on the 0-based line `index`, None if the line is blank
+/// or a comment. Verbs are case-insensitive, and any whitespace separates the
+/// verb from its distance.
+pub fn parse_line(index: usize, line: &str) -> Result<Option<Direction>, Violation> {
+    let command = line.split_once('#').map_or(line, |(command, _)| command);
+    let mut tokens = command.split_whitespace();
+    let Some(verb) = tokens.next() else {
+        return Ok(None);
+    };
+    let direction: fn(u32) -> Direction = match verb.to_ascii_lowercase().as_str() {
+        "forward" => Direction::Forward,
+        "down" => Direction::Down,
+        "up" => Direction::Up,
+        _ => {
+            let message = format!("unknown command {:?}", verb);
+            return Err(Violation::token(index, line, verb, message));
+        }
+    };
+    let Some(distance) = tokens.next() else {
+        let message = format!("missing distance after {:?}", verb);
+        return Err(Violation::line(index, message));
+    };
+    // Only digits, as `parse` would also take a leading `+`
+    let distance = Some(distance)
+        .filter(|d| d.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|d| d.parse().ok())
+        .ok_or_else(|| {
+            let message = format!("invalid distance {:?}", distance);
+            Violation::token(index, line, distance, message)
+        })?;
+    if let Some(extra) = tokens.next() {
+        let message = format!("unexpected {:?} after the distance", extra);
+        return Err(Violation::token(index, line, extra, message));
+    }
+    Ok(Some(direction(distance)))
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = Violation;
+
+    /// Parses a single command, which may not be blank or only a comment
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        parse_line(0, line)?.ok_or_else(|| Violation::line(0, "missing command"))
+    }
+}
+
+/// How the commands move the submarine
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Model {
+    /// Part one: `down` and `up` change the depth directly
+    Simple,
+    /// Part two: `down` and `up` change the aim, and `forward` dives along it
+    Aim,
+}
+
+/// Where the submarine is after `step` commands. Depths above the surface are negative.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub step: usize,
+    pub horizontal: i64,
+    pub depth: i64,
+    /// Always 0 in the simple model
+    pub aim: i64,
+}
+
+/// What happens when a command would take the submarine above the surface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Surface {
+    /// Keep going with a negative depth
+    #[default]
+    Allow,
+    /// Stop at the surface, at depth 0
+    Clamp,
+    /// Fail with `CourseError::AboveSurface`
+    Reject,
+}
+
+/// Limits on where the submarine may go
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Policy {
+    pub surface: Surface,
+    /// Courses going deeper than this fail with `CourseError::TooDeep`
+    pub max_depth: Option<i64>,
+}
+
+/// The first command taking the submarine somewhere the policy forbids,
+/// or further than an `i64` can track
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CourseError {
+    AboveSurface {
+        step: usize,
+        depth: i64,
+    },
+    TooDeep {
+        step: usize,
+        depth: i64,
+        max_depth: i64,
+    },
+    /// The position after `step` commands, or the product of the final
+    /// horizontal position and depth, doesn't fit in an `i64`
+    Overflow {
+        step: usize,
+    },
+}
+
+impl fmt::Display for CourseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CourseError::AboveSurface { step, depth } => write!(
+                f,
+                "step {} takes the submarine above the surface, to depth {}",
+                step, depth
+            ),
+            CourseError::TooDeep {
+                step,
+                depth,
+                max_depth,
+            } => write!(
+                f,
+                "step {} takes the submarine to depth {}, below the limit of {}",
+                step, depth, max_depth
+            ),
+            CourseError::Overflow { step } => {
+                write!(f, "the position overflows at step {}", step)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CourseError {}
+
+impl Position {
+    /// The position after following one more command, going wherever it
+    /// leads as long as it fits in an `i64`
+    pub fn follow(self, direction: Direction, model: Model) -> Result<Self, CourseError> {
+        let step = self.step + 1;
+        let overflow = CourseError::Overflow { step };
+        let mut next = Position { step, ..self };
+        match (direction, model) {
+            (Direction::Forward(dist), Model::Simple) => {
+                next.horizontal = self.horizontal.checked_add(dist.into()).ok_or(overflow)?
+            }
+            (Direction::Down(dist), Model::Simple) => {
+                next.depth = self.depth.checked_add(dist.into()).ok_or(overflow)?
+            }
+            (Direction::Up(dist), Model::Simple) => {
+                next.depth = self.depth.checked_sub(dist.into()).ok_or(overflow)?
+            }
+            (Direction::Forward(dist), Model::Aim) => {
+                next.horizontal = self.horizontal.checked_add(dist.into()).ok_or(overflow)?;
+                next.depth = i64::from(dist)
+                    .checked_mul(self.aim)
+                    .and_then(|dive| self.depth.checked_add(dive))
+                    .ok_or(overflow)?;
+            }
+            (Direction::Down(dist), Model::Aim) => {
+                next.aim = self.aim.checked_add(dist.into()).ok_or(overflow)?
+            }
+            (Direction::Up(dist), Model::Aim) => {
+                next.aim = self.aim.checked_sub(dist.into()).ok_or(overflow)?
+            }
+        }
+        Ok(next)
+    }
+
+    /// The product of the horizontal position and depth
+    fn product(&self) -> Result<i64, CourseError> {
+        self.horizontal
+            .checked_mul(self.depth)
+            .ok_or(CourseError::Overflow { step: self.step })
+    }
+
+    /// The position after following one more command within `policy`
+    pub fn follow_within(
+        self,
+        direction: Direction,
+        model: Model,
+        policy: &Policy,
+    ) -> Result<Self, CourseError> {
+        let mut next = self.follow(direction, model)?;
+        if next.depth < 0 {
+            match policy.surface {
+                Surface::Allow => {}
+                Surface::Clamp => next.depth = 0,
+                Surface::Reject => {
+                    return Err(CourseError::AboveSurface {
+                        step: next.step,
+                        depth: next.depth,
+                    })
+                }
+            }
+        }
+        match policy.max_depth {
+            Some(max_depth) if next.depth > max_depth => Err(CourseError::TooDeep {
+                step: next.step,
+                depth: next.depth,
+                max_depth,
+            }),
+            _ => Ok(next),
+        }
+    }
+}
+
+/// Every position along the course, starting with the surface at step 0
+pub fn trajectory(
+    input: impl IntoIterator<Item = impl Borrow<Direction>>,
+    model: Model,
+) -> Result<Vec<Position>, CourseError> {
+    trajectory_within(input, model, &Policy::default())
+}
+
+/// Every position along the course within `policy`, starting with the surface at step 0
+pub fn trajectory_within(
+    input: impl IntoIterator<Item = impl Borrow<Direction>>,
+    model: Model,
+    policy: &Policy,
+) -> Result<Vec<Position>, CourseError> {
+    let mut position = Position::default();
+    let mut positions = vec![position];
+    for direction in input {
+        position = position.follow_within(*direction.borrow(), model, policy)?;
+        positions.push(position);
+    }
+    Ok(positions)
+}
+
+/// The product of the final horizontal position and depth, following the course within `policy`
+pub fn final_position_within(
+    input: impl IntoIterator<Item = impl Borrow<Direction>>,
+    model: Model,
+    policy: &Policy,
+) -> Result<i64, CourseError> {
+    let end = input
+        .into_iter()
+        .try_fold(Position::default(), |position, direction| {
+            position.follow_within(*direction.borrow(), model, policy)
+        })?;
+    end.product()
+}
+
+pub fn final_position(
+    input: impl IntoIterator<Item = impl Borrow<Direction>>,
+) -> Result<i64, CourseError> {
+    final_position_within(input, Model::Simple, &Policy::default())
+}
+
+pub fn final_position_with_aim(
+    input: impl IntoIterator<Item = impl Borrow<Direction>>,
+) -> Result<i64, CourseError> {
+    final_position_within(input, Model::Aim, &Policy::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(final_position(parse_input(EXAMPLE).unwrap()), Ok(150));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(
+            final_position_with_aim(parse_input(EXAMPLE).unwrap()),
+            Ok(900)
+        );
+    }
+
+    #[test]
+    fn multi_digit_distances_and_comments() {
+        let course = "\
+# Leaving the harbour
+FORWARD   12
+\tDown 100  # dive
+   up\t0
+
+Forward 4294967295";
+        assert_eq!(
+            parse_input(course),
+            Ok(vec![
+                Direction::Forward(12),
+                Direction::Down(100),
+                Direction::Up(0),
+                Direction::Forward(u32::MAX),
+            ])
+        );
+    }
+
+    #[test]
+    fn errors_name_the_line_and_token() {
+        let error = |course: &str| parse_input(course).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 1\nsideways 2"),
+            "2:1: unknown command \"sideways\""
+        );
+        assert_eq!(error("down -3"), "1:6: invalid distance \"-3\"");
+        assert_eq!(
+            error("up 4294967296"),
+            "1:4: invalid distance \"4294967296\""
+        );
+        assert_eq!(error("\n\nup  +3"), "3:5: invalid distance \"+3\"");
+        assert_eq!(error("up 3 4"), "1:6: unexpected \"4\" after the distance");
+        assert_eq!(
+            error("forward # 3"),
+            "1: missing distance after \"forward\""
+        );
+        assert_eq!(
+            solve("dive 3"),
+            Err(Error::InvalidInput(Violation::token(
+                0,
+                "dive 3",
+                &"dive 3"[..4],
+                "unknown command \"dive\""
+            )))
+        );
+    }
+
+    #[test]
+    fn streams_commands() {
+        let commands: Vec<Direction> = read_commands(EXAMPLE.as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(final_position_with_aim(commands), Ok(900));
+
+        let mut commands = read_commands("up 1\n\nup x\n".as_bytes());
+        assert!(commands.next().unwrap().is_ok());
+        let error = commands.next().unwrap().unwrap_err();
+        assert_eq!(error.to_string(), "3:4: invalid distance \"x\"");
+    }
+
+    #[test]
+    fn trajectories() {
+        let course = parse_input(EXAMPLE).unwrap();
+        let simple = trajectory(&course, Model::Simple).unwrap();
+        let aim = trajectory(&course, Model::Aim).unwrap();
+        assert_eq!(simple.len(), 7);
+        assert_eq!(simple[0], Position::default());
+        let last = |positions: &[Position]| *positions.last().unwrap();
+        assert_eq!(
+            last(&simple),
+            Position {
+                step: 6,
+                horizontal: 15,
+                depth: 10,
+                aim: 0
+            }
+        );
+        assert_eq!(
+            last(&aim),
+            Position {
+                step: 6,
+                horizontal: 15,
+                depth: 60,
+                aim: 10
+            }
+        );
+        // After `forward 5`, `down 5`, `forward 8` with aim 5
+        assert_eq!((aim[3].horizontal, aim[3].depth), (13, 40));
+    }
+
+    #[test]
+    fn above_the_surface() {
+        let course = parse_input("forward 2\ndown 1\nup 3\nforward 1\ndown 4").unwrap();
+        assert_eq!(final_position(&course), Ok(3 * 2));
+        let policy = |surface| Policy {
+            surface,
+            max_depth: None,
+        };
+        assert_eq!(
+            final_position_within(&course, Model::Simple, &policy(Surface::Clamp)),
+            Ok(3 * 4)
+        );
+        assert_eq!(
+            final_position_within(&course, Model::Simple, &policy(Surface::Reject)),
+            Err(CourseError::AboveSurface { step: 3, depth: -2 })
+        );
+        // With aim, going up only dives upwards once the submarine moves forward
+        assert_eq!(final_position_with_aim(&course), Ok(3 * -2));
+        assert_eq!(
+            trajectory_within(&course, Model::Aim, &policy(Surface::Reject)),
+            Err(CourseError::AboveSurface { step: 4, depth: -2 })
+        );
+    }
+
+    #[test]
+    fn depth_limit() {
+        let course = parse_input(EXAMPLE).unwrap();
+        let policy = Policy {
+            surface: Surface::Reject,
+            max_depth: Some(50),
+        };
+        assert_eq!(
+            final_position_within(&course, Model::Simple, &policy),
+            Ok(150)
+        );
+        let error = final_position_within(&course, Model::Aim, &policy).unwrap_err();
+        assert_eq!(
+            error,
+            CourseError::TooDeep {
+                step: 6,
+                depth: 60,
+                max_depth: 50
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "step 6 takes the submarine to depth 60, below the limit of 50"
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let course = parse_input("down 4294967295\nforward 4294967295").unwrap();
+        // 4294967295² doesn't fit in an i64, as the final product or the depth
+        assert_eq!(
+            final_position(&course),
+            Err(CourseError::Overflow { step: 2 })
+        );
+        assert_eq!(
+            trajectory(&course, Model::Aim),
+            Err(CourseError::Overflow { step: 2 })
+        );
+        assert_eq!(
+            solve("down 4294967295\nforward 4294967295"),
+            Err(Error::Overflow(
+                "the position overflows at step 2".to_string()
+            ))
+        );
+    }
+}
@@ -1,13 +1,54 @@
-use std::fs;
+use day_02::script::Script;
+use day_02::{final_position, final_position_with_aim, parse_input, trajectory, Model};
+use std::fmt::Display;
 use std::time::Instant;
+use std::{env, fs, process};
+
+const USAGE: &str = "\
+Usage: day_02 [--csv simple|aim | --svg | --script file]
+  Without options, solves input.txt. --csv prints the trajectory under one
+  model, and --svg plots the depth profile under both. --script solves a
+  course script with repeat blocks, macros and includes instead.";
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let [flag, path] = &args[..] {
+        if flag == "--script" {
+            solve_script(path);
+            return;
+        }
+    }
     let contents = fs::read_to_string("input.txt").expect("Something went wrong reading the file");
     let now = Instant::now();
-    let parsed_input = parse_input(&contents);
+    let parsed_input = parse_input(&contents).unwrap_or_else(|violation| {
+        eprintln!("input.txt:{}", violation);
+        process::exit(1);
+    });
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {}
+        ["--csv", model] => {
+            let model = match model {
+                "simple" => Model::Simple,
+                "aim" => Model::Aim,
+                _ => usage(),
+            };
+            let positions = trajectory(&parsed_input, model).unwrap_or_else(|err| exit(err));
+            print!("{}", day_02::trajectory::to_csv(&positions));
+            return;
+        }
+        ["--svg"] => {
+            let simple = trajectory(&parsed_input, Model::Simple).unwrap_or_else(|err| exit(err));
+            let aim = trajectory(&parsed_input, Model::Aim).unwrap_or_else(|err| exit(err));
+            let svg = day_02::trajectory::to_svg(&[(Model::Simple, &simple), (Model::Aim, &aim)]);
+            print!("{}", svg);
+            return;
+        }
+        _ => usage(),
+    }
 
-    let part_one = final_position(&parsed_input);
-    let part_two = final_position_with_aim(&parsed_input);
+    let part_one = final_position(&parsed_input).unwrap_or_else(|err| exit(err));
+    let part_two = final_position_with_aim(&parsed_input).unwrap_or_else(|err| exit(err));
     let time = now.elapsed().as_micros();
 
     println!(
@@ -16,61 +57,25 @@ fn main() {
     );
 }
 
-fn parse_input(contents: &str) -> Vec<Direction> {
-    contents
-        .lines()
-        .map(|line| line.try_into().expect("Invalid input"))
-        .collect()
-}
-
-enum Direction {
-    Forward(u32),
-    Down(u32),
-    Up(u32),
-}
-impl TryFrom<&str> for Direction {
-    type Error = ();
-
-    fn try_from(line: &str) -> Result<Self, Self::Error> {
-        let distance = (&line[line.len() - 1..]).parse().expect("Not an integer");
+/// Streams the script's commands through both models
+fn solve_script(path: &str) {
+    let script = Script::load(path).unwrap_or_else(|err| exit(err));
+    let now = Instant::now();
+    let part_one = final_position(script.directions()).unwrap_or_else(|err| exit(err));
+    let part_two = final_position_with_aim(script.directions()).unwrap_or_else(|err| exit(err));
+    let time = now.elapsed().as_micros();
 
-        match &line[0..line.len() - 2] {
-            "forward" => Ok(Direction::Forward(distance)),
-            "down" => Ok(Direction::Down(distance)),
-            "up" => Ok(Direction::Up(distance)),
-            _ => Err(()),
-        }
-    }
+    println!(
+        "Part one: {}\nPart two: {}\nTime: {} μs",
+        part_one, part_two, time
+    );
 }
 
-fn final_position(input: &[Direction]) -> u32 {
-    let mut horizontal = 0;
-    let mut depth = 0;
-
-    for direction in input {
-        match direction {
-            Direction::Forward(dist) => horizontal += dist,
-            Direction::Down(dist) => depth += dist,
-            Direction::Up(dist) => depth -= dist,
-        }
-    }
-    horizontal * depth
+fn usage() -> ! {
+    exit(USAGE)
 }
 
-fn final_position_with_aim(input: &[Direction]) -> u32 {
-    let mut aim = 0;
-    let mut horizontal = 0;
-    let mut depth = 0;
-
-    for direction in input {
-        match direction {
-            Direction::Forward(dist) => {
-                horizontal += dist;
-                depth += dist * aim;
-            }
-            Direction::Down(dist) => aim += dist,
-            Direction::Up(dist) => aim -= dist,
-        }
-    }
-    horizontal * depth
+fn exit(message: impl Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
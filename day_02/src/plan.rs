@@ -0,0 +1,184 @@
+//! Plans the shortest course reaching a target position, to build fixtures
+//! and explore the models.
+//!
+//! Following a course only ever merges consecutive commands of the same kind,
+//! so a shortest course alternates `forward` with aim changes. Under the aim
+//! model that leaves a handful of shapes of up to four commands, tried from
+//! shortest to longest.
+
+use crate::Direction;
+
+/// The shortest course ending `horizontal` forward and at `depth` in the simple
+/// model. Depths beyond what one command can dive take several commands.
+pub fn course(horizontal: u32, depth: i64) -> Vec<Direction> {
+    let mut course = Vec::new();
+    if horizontal > 0 {
+        course.push(Direction::Forward(horizontal));
+    }
+    let mut remaining = depth;
+    while remaining != 0 {
+        let step = remaining.clamp(-i64::from(u32::MAX), i64::from(u32::MAX));
+        course.extend(turn(step));
+        remaining -= step;
+    }
+    course
+}
+
+/// The shortest course ending `horizontal` forward, at `depth` and with `aim`
+/// in the aim model, None if the depth is unreachable because the course
+/// never moves forward. The ranges keep every command within a `u32`.
+pub fn course_with_aim(horizontal: u32, depth: i32, aim: i32) -> Option<Vec<Direction>> {
+    let (h, d, a) = (i64::from(horizontal), i64::from(depth), i64::from(aim));
+    let forward = |distance: i64| Direction::Forward(distance as u32);
+
+    if h == 0 {
+        return (d == 0).then(|| turn(a).into_iter().collect());
+    }
+    if d == 0 {
+        return Some([Some(forward(h)), turn(a)].into_iter().flatten().collect());
+    }
+    // Diving along a single aim, then turning to the final aim
+    if d % h == 0 {
+        let x = d / h;
+        return Some(
+            [turn(x), Some(forward(h)), turn(a - x)]
+                .into_iter()
+                .flatten()
+                .collect(),
+        );
+    }
+    // Moving forward level, then diving along the final aim
+    if a != 0 && d % a == 0 && (1..h).contains(&(d / a)) {
+        let dive = d / a;
+        return Some(vec![forward(h - dive), turn(a).unwrap(), forward(dive)]);
+    }
+    // Otherwise one unit forward at aim `depth` does the diving, which needs
+    // two forward commands and so at least two units
+    (h >= 2).then(|| {
+        [Some(forward(h - 1)), turn(d), Some(forward(1)), turn(a - d)]
+            .into_iter()
+            .flatten()
+            .collect()
+    })
+}
+
+/// The command changing the depth or aim by `change`, None for no change
+fn turn(change: i64) -> Option<Direction> {
+    match change {
+        0 => None,
+        1.. => Some(Direction::Down(change as u32)),
+        _ => Some(Direction::Up(change.unsigned_abs() as u32)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{final_position_with_aim, trajectory, Model, Position};
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    fn end(course: &[Direction], model: Model) -> (i64, i64, i64) {
+        let Position {
+            horizontal,
+            depth,
+            aim,
+            ..
+        } = *trajectory(course, model).unwrap().last().unwrap();
+        (horizontal, depth, aim)
+    }
+
+    #[test]
+    fn example_target() {
+        let course = course_with_aim(15, 60, 10).unwrap();
+        assert_eq!(
+            course,
+            [
+                Direction::Down(4),
+                Direction::Forward(15),
+                Direction::Down(6)
+            ]
+        );
+        assert_eq!(final_position_with_aim(&course), Ok(900));
+    }
+
+    #[test]
+    fn unreachable_without_moving_forward() {
+        assert_eq!(course_with_aim(0, 1, 0), None);
+        assert_eq!(course_with_aim(0, 0, -3), Some(vec![Direction::Up(3)]));
+        assert_eq!(course_with_aim(0, 0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn deep_simple_courses_take_several_commands() {
+        let depth = 2 * i64::from(u32::MAX) + 1;
+        let course = course(1, -depth);
+        assert_eq!(course.len(), 4);
+        assert_eq!(end(&course, Model::Simple), (1, -depth, 0));
+    }
+
+    /// Every end position reachable in up to three commands of distances up
+    /// to 6, with the fewest commands reaching it
+    fn shortest_courses() -> HashMap<(i64, i64, i64), usize> {
+        let commands: Vec<Direction> = (0..=6)
+            .flat_map(|n| [Direction::Forward(n), Direction::Down(n), Direction::Up(n)])
+            .collect();
+        let mut shortest = HashMap::from([((0, 0, 0), 0)]);
+        let mut frontier = vec![Position::default()];
+        for length in 1..=3 {
+            let mut next = Vec::new();
+            for position in frontier {
+                for &command in &commands {
+                    let moved = position.follow(command, Model::Aim).unwrap();
+                    let key = (moved.horizontal, moved.depth, moved.aim);
+                    shortest.entry(key).or_insert(length);
+                    next.push(moved);
+                }
+            }
+            frontier = next;
+        }
+        shortest
+    }
+
+    #[test]
+    fn no_shorter_course_exists() {
+        let shortest = shortest_courses();
+        for h in 0..=6 {
+            for d in -12..=12 {
+                for a in -6..=6 {
+                    let Some(course) = course_with_aim(h, d, a) else {
+                        assert_eq!(h, 0);
+                        continue;
+                    };
+                    let target = (i64::from(h), i64::from(d), i64::from(a));
+                    if let Some(&length) = shortest.get(&target) {
+                        assert!(
+                            course.len() <= length,
+                            "{:?} for {:?}, but {} commands are enough",
+                            course,
+                            target,
+                            length
+                        );
+                    }
+                    assert!(course.len() <= 4);
+                }
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn aim_courses_round_trip(horizontal in 1..=u32::MAX, depth: i32, aim: i32) {
+            let course = course_with_aim(horizontal, depth, aim).unwrap();
+            let target = (i64::from(horizontal), i64::from(depth), i64::from(aim));
+            prop_assert_eq!(end(&course, Model::Aim), target);
+            prop_assert_eq!(final_position_with_aim(&course), Ok(target.0 * target.1));
+        }
+
+        #[test]
+        fn simple_courses_round_trip(horizontal: u32, depth in -(1i64 << 40)..1 << 40) {
+            let course = course(horizontal, depth);
+            prop_assert_eq!(end(&course, Model::Simple), (i64::from(horizontal), depth, 0));
+        }
+    }
+}
@@ -0,0 +1,582 @@
+//! Course scripts: the plain command format extended with blocks, which
+//! expand lazily into the same `Direction` stream.
+//!
+//! ```text
+//! # Macros can be defined anywhere at the top level, and used by name
+//! macro dive {
+//!     down 5
+//!     forward 3
+//! }
+//! include "harbour.course"
+//! repeat 3 {
+//!     dive
+//!     up 5
+//! }
+//! ```
+//!
+//! Included paths are relative to the including file, and share its macros.
+
+use crate::{parse_line, Direction};
+use aoc_common::Violation;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// Deeper includes are taken to be a cycle through differently spelled paths
+const MAX_INCLUDE_DEPTH: usize = 64;
+
+/// A problem in a script, at a line of one of its files
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptError {
+    pub file: PathBuf,
+    pub violation: Violation,
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file.display(), self.violation)
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Location {
+    file: Rc<Path>,
+    /// 0-based line index
+    index: usize,
+}
+
+impl Location {
+    fn error(&self, message: impl Into<String>) -> ScriptError {
+        ScriptError {
+            file: self.file.to_path_buf(),
+            violation: Violation::line(self.index, message),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Item {
+    Command(Direction),
+    Repeat {
+        times: u32,
+        body: Vec<Item>,
+        /// Commands in one pass of the body, counted once the calls are checked
+        len: u128,
+    },
+    Call(String, Location),
+}
+
+#[derive(Debug)]
+struct Macro {
+    body: Vec<Item>,
+    location: Location,
+    /// Commands the body expands to, counted once the calls are checked
+    len: u128,
+}
+
+/// A parsed script, with its includes read and its macros checked
+#[derive(Debug)]
+pub struct Script {
+    items: Vec<Item>,
+    macros: HashMap<String, Macro>,
+    len: u128,
+}
+
+/// A block waiting for its closing `}`
+enum Open {
+    Repeat(u32),
+    Macro(String),
+}
+
+impl Script {
+    /// Reads the script at `path` and the files it includes
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScriptError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(|err| ScriptError {
+            file: path.to_path_buf(),
+            violation: Violation::line(0, format!("failed to read the file: {}", err)),
+        })?;
+        Script::parse(path, &source, |path| fs::read_to_string(path))
+    }
+
+    /// Parses `source`, the script at `path`, reading included files with `read`
+    pub fn parse(
+        path: impl AsRef<Path>,
+        source: &str,
+        mut read: impl FnMut(&Path) -> io::Result<String>,
+    ) -> Result<Self, ScriptError> {
+        let mut script = Script {
+            items: Vec::new(),
+            macros: HashMap::new(),
+            len: 0,
+        };
+        let mut parser = Parser {
+            macros: &mut script.macros,
+            read: &mut read,
+            includes: Vec::new(),
+        };
+        script.items = parser.parse_file(Rc::from(path.as_ref()), source)?;
+        let callees_first = script.check_calls()?;
+        script.count(callees_first);
+        Ok(script)
+    }
+
+    /// Streams the commands the script expands to
+    pub fn directions(&self) -> Directions<'_> {
+        Directions {
+            script: self,
+            stack: vec![Frame {
+                items: &self.items,
+                next: 0,
+                repeats_left: 0,
+            }],
+        }
+    }
+
+    /// The number of commands the script expands to, without expanding it
+    pub fn len(&self) -> u128 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Counts the commands of every macro, block and the whole script, once
+    /// each, taking the macros in an order where each only calls counted ones
+    fn count(&mut self, callees_first: Vec<String>) {
+        for name in callees_first {
+            let mut body = std::mem::take(&mut self.macros.get_mut(&name).unwrap().body);
+            let len = count_items(&mut body, &self.macros);
+            let defined = self.macros.get_mut(&name).unwrap();
+            (defined.body, defined.len) = (body, len);
+        }
+        self.len = count_items(&mut self.items, &self.macros);
+    }
+
+    /// Checks every call names a macro, and that no macro calls itself.
+    /// Returns the names of the macros, each after those it calls.
+    fn check_calls(&self) -> Result<Vec<String>, ScriptError> {
+        let mut names: Vec<&String> = self.macros.keys().collect();
+        // Report the same cycle whatever the hash order
+        names.sort_by_key(|name| {
+            let location = &self.macros[*name].location;
+            (location.file.clone(), location.index)
+        });
+        let mut done = Vec::new();
+        for name in names {
+            self.check_macro(name, &mut Vec::new(), &mut done)?;
+        }
+        self.check_items(&self.items, &mut Vec::new(), &mut done)?;
+        Ok(done.into_iter().map(String::from).collect())
+    }
+
+    fn check_macro<'a>(
+        &'a self,
+        name: &'a str,
+        calling: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Result<(), ScriptError> {
+        if done.contains(&name) {
+            return Ok(());
+        }
+        calling.push(name);
+        self.check_items(&self.macros[name].body, calling, done)?;
+        calling.pop();
+        done.push(name);
+        Ok(())
+    }
+
+    fn check_items<'a>(
+        &'a self,
+        items: &'a [Item],
+        calling: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Result<(), ScriptError> {
+        for item in items {
+            match item {
+                Item::Command(_) => {}
+                Item::Repeat { body, .. } => self.check_items(body, calling, done)?,
+                Item::Call(name, location) => {
+                    if !self.macros.contains_key(name) {
+                        return Err(location.error(format!("unknown macro {:?}", name)));
+                    }
+                    if let Some(start) = calling.iter().position(|caller| caller == name) {
+                        let cycle = calling[start..].join(" -> ");
+                        return Err(location.error(format!(
+                            "recursive call to macro {:?} ({} -> {})",
+                            name, cycle, name
+                        )));
+                    }
+                    self.check_macro(name, calling, done)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Parser<'a, R> {
+    macros: &'a mut HashMap<String, Macro>,
+    read: &'a mut R,
+    /// The files being parsed, outermost first
+    includes: Vec<Rc<Path>>,
+}
+
+impl<R: FnMut(&Path) -> io::Result<String>> Parser<'_, R> {
+    fn parse_file(&mut self, file: Rc<Path>, source: &str) -> Result<Vec<Item>, ScriptError> {
+        self.includes.push(file.clone());
+        // The items of the top level, then of each open block
+        let mut blocks: Vec<(Open, Location, Vec<Item>)> = Vec::new();
+        let mut items = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let location = Location {
+                file: file.clone(),
+                index,
+            };
+            let code = line.split_once('#').map_or(line, |(code, _)| code);
+            let tokens: Vec<&str> = code.split_whitespace().collect();
+            let current = blocks.last_mut().map_or(&mut items, |block| &mut block.2);
+
+            match tokens[..] {
+                [] => {}
+                ["}"] => {
+                    let Some((open, start, body)) = blocks.pop() else {
+                        return Err(location.error("unmatched \"}\""));
+                    };
+                    match open {
+                        Open::Repeat(times) => {
+                            let current =
+                                blocks.last_mut().map_or(&mut items, |block| &mut block.2);
+                            current.push(Item::Repeat {
+                                times,
+                                body,
+                                len: 0,
+                            });
+                        }
+                        Open::Macro(name) => {
+                            let defined = Macro {
+                                body,
+                                location: start,
+                                len: 0,
+                            };
+                            if let Some(previous) = self.macros.insert(name.clone(), defined) {
+                                let message = format!(
+                                    "macro {:?} is already defined at {}:{}",
+                                    name,
+                                    previous.location.file.display(),
+                                    previous.location.index + 1
+                                );
+                                return Err(location.error(message));
+                            }
+                        }
+                    }
+                }
+                ["repeat", times, "{"] => {
+                    let times = times.parse().map_err(|_| {
+                        let message = format!("invalid repeat count {:?}", times);
+                        ScriptError {
+                            file: file.to_path_buf(),
+                            violation: Violation::token(index, line, times, message),
+                        }
+                    })?;
+                    blocks.push((Open::Repeat(times), location, Vec::new()));
+                }
+                ["macro", name, "{"] => {
+                    if !blocks.is_empty() {
+                        return Err(location.error("macros can only be defined at the top level"));
+                    }
+                    if !is_macro_name(name) {
+                        let message = format!("invalid macro name {:?}", name);
+                        return Err(ScriptError {
+                            file: file.to_path_buf(),
+                            violation: Violation::token(index, line, name, message),
+                        });
+                    }
+                    blocks.push((Open::Macro(name.to_string()), location, Vec::new()));
+                }
+                ["include", path] => {
+                    let path = path.trim_matches('"');
+                    let included = file.parent().unwrap_or(Path::new("")).join(path);
+                    let included = self.include(&included, &location)?;
+                    current.extend(included);
+                }
+                [name] if is_macro_name(name) => {
+                    current.push(Item::Call(name.to_string(), location));
+                }
+                _ => {
+                    let command = parse_line(index, line).map_err(|violation| ScriptError {
+                        file: file.to_path_buf(),
+                        violation,
+                    })?;
+                    current.extend(command.map(Item::Command));
+                }
+            }
+        }
+
+        if let Some((_, start, _)) = blocks.pop() {
+            return Err(start.error("block is never closed with \"}\""));
+        }
+        self.includes.pop();
+        Ok(items)
+    }
+
+    fn include(&mut self, path: &Path, from: &Location) -> Result<Vec<Item>, ScriptError> {
+        if self.includes.iter().any(|file| **file == *path) {
+            return Err(from.error(format!("{} includes itself", path.display())));
+        }
+        if self.includes.len() >= MAX_INCLUDE_DEPTH {
+            return Err(from.error("includes are nested too deeply"));
+        }
+        let source = (self.read)(path)
+            .map_err(|err| from.error(format!("failed to include {}: {}", path.display(), err)))?;
+        self.parse_file(Rc::from(path), &source)
+    }
+}
+
+/// Counts the commands `items` expand to, and those of one pass of each
+/// block among them, given the counts of the macros they call
+fn count_items(items: &mut [Item], macros: &HashMap<String, Macro>) -> u128 {
+    items
+        .iter_mut()
+        .map(|item| match item {
+            Item::Command(_) => 1,
+            Item::Repeat { times, body, len } => {
+                *len = count_items(body, macros);
+                u128::from(*times).saturating_mul(*len)
+            }
+            Item::Call(name, _) => macros[name.as_str()].len,
+        })
+        .fold(0, u128::saturating_add)
+}
+
+/// Names that can't be mistaken for a keyword or a command without distance
+fn is_macro_name(name: &str) -> bool {
+    let keyword = ["repeat", "macro", "include", "forward", "down", "up"]
+        .iter()
+        .any(|keyword| name.eq_ignore_ascii_case(keyword));
+    !keyword
+        && name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// The commands of a script, expanded as they're needed
+pub struct Directions<'a> {
+    script: &'a Script,
+    stack: Vec<Frame<'a>>,
+}
+
+struct Frame<'a> {
+    items: &'a [Item],
+    next: usize,
+    /// Times the items are still to be repeated after this pass
+    repeats_left: u32,
+}
+
+impl Iterator for Directions<'_> {
+    type Item = Direction;
+
+    fn next(&mut self) -> Option<Direction> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(item) = frame.items.get(frame.next) else {
+                if frame.repeats_left > 0 {
+                    frame.repeats_left -= 1;
+                    frame.next = 0;
+                } else {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+            match item {
+                Item::Command(direction) => return Some(*direction),
+                // Repeating a block without commands would spin for nothing
+                Item::Repeat { times, body, len } if *times > 0 && *len > 0 => {
+                    self.stack.push(Frame {
+                        items: body,
+                        next: 0,
+                        repeats_left: times - 1,
+                    })
+                }
+                Item::Repeat { .. } => {}
+                Item::Call(name, _) => self.stack.push(Frame {
+                    items: &self.script.macros[name].body,
+                    next: 0,
+                    repeats_left: 0,
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{final_position, final_position_with_aim, parse_input};
+
+    fn parse(source: &str) -> Result<Script, ScriptError> {
+        parse_with_files(source, &[])
+    }
+
+    /// Parses `source` as `course.txt`, with `files` available to include
+    fn parse_with_files(source: &str, files: &[(&str, &str)]) -> Result<Script, ScriptError> {
+        Script::parse("course.txt", source, |path| {
+            files
+                .iter()
+                .find(|(name, _)| Path::new(name) == path)
+                .map(|(_, source)| source.to_string())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "not found"))
+        })
+    }
+
+    fn error(source: &str) -> String {
+        parse(source).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn plain_courses_are_scripts() {
+        let example = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+        let script = parse(example).unwrap();
+        let directions: Vec<Direction> = script.directions().collect();
+        assert_eq!(directions, parse_input(example).unwrap());
+        assert_eq!(final_position(script.directions()), Ok(150));
+        assert_eq!(final_position_with_aim(script.directions()), Ok(900));
+    }
+
+    #[test]
+    fn repeats_and_macros() {
+        let script = parse(
+            "\
+repeat 2 {
+    dive  # defined below
+    repeat 3 {
+        forward 1
+    }
+}
+macro dive {
+    down 2
+}
+repeat 0 {
+    up 100
+}",
+        )
+        .unwrap();
+        let directions: Vec<Direction> = script.directions().collect();
+        let once = [
+            Direction::Down(2),
+            Direction::Forward(1),
+            Direction::Forward(1),
+            Direction::Forward(1),
+        ];
+        assert_eq!(directions, [once, once].concat());
+        assert_eq!(script.len(), 8);
+    }
+
+    #[test]
+    fn huge_repeats_stream() {
+        let script = parse("repeat 4000000000 {\nrepeat 4000000000 {\nforward 1\n}\n}").unwrap();
+        assert_eq!(script.len(), 16_000_000_000_000_000_000);
+        assert_eq!(script.directions().take(5).count(), 5);
+        let empty = parse("macro nothing {\n}\nrepeat 4000000000 {\nnothing\n}").unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.directions().next(), None);
+    }
+
+    #[test]
+    fn deeply_nested_macros_stream() {
+        // Each macro calls the one before twice, so m34 expands to 2^34 commands
+        let mut source = String::from("macro m0 {\nforward 1\n}\n");
+        for i in 1..=34 {
+            source += &format!("macro m{} {{\nm{}\nm{}\n}}\n", i, i - 1, i - 1);
+        }
+        source += "repeat 2 {\nm34\n}";
+        let script = parse(&source).unwrap();
+        assert_eq!(script.len(), 1 << 35);
+        assert_eq!(script.directions().next(), Some(Direction::Forward(1)));
+    }
+
+    #[test]
+    fn includes() {
+        let script = parse_with_files(
+            "include \"parts/dive.txt\"\ndive\nforward 1",
+            &[
+                (
+                    "parts/dive.txt",
+                    "macro dive {\ndown 1\n}\ninclude deeper.txt",
+                ),
+                ("parts/deeper.txt", "down 10"),
+            ],
+        )
+        .unwrap();
+        let directions: Vec<Direction> = script.directions().collect();
+        assert_eq!(
+            directions,
+            [
+                Direction::Down(10),
+                Direction::Down(1),
+                Direction::Forward(1)
+            ]
+        );
+
+        let cycle = parse_with_files(
+            "include a.txt",
+            &[("a.txt", "forward 1\ninclude course.txt")],
+        );
+        assert_eq!(
+            cycle.unwrap_err().to_string(),
+            "a.txt:2: course.txt includes itself"
+        );
+        assert_eq!(
+            error("\ninclude missing.txt"),
+            "course.txt:2: failed to include missing.txt: not found"
+        );
+    }
+
+    #[test]
+    fn errors_name_the_file_and_line() {
+        assert_eq!(
+            error("forward 1\nsideways\n"),
+            "course.txt:2: unknown macro \"sideways\""
+        );
+        assert_eq!(
+            error("sideways 2"),
+            "course.txt:1:1: unknown command \"sideways\""
+        );
+        assert_eq!(
+            error("macro a {\nb\n}\nmacro b {\nforward 1\na\n}\na"),
+            "course.txt:6: recursive call to macro \"a\" (a -> b -> a)"
+        );
+        assert_eq!(
+            error("macro a {\na\n}"),
+            "course.txt:2: recursive call to macro \"a\" (a -> a)"
+        );
+        assert_eq!(
+            error("repeat -1 {\n}"),
+            "course.txt:1:8: invalid repeat count \"-1\""
+        );
+        assert_eq!(
+            error("repeat 2 {\nforward 1"),
+            "course.txt:1: block is never closed with \"}\""
+        );
+        assert_eq!(error("forward 1\n}"), "course.txt:2: unmatched \"}\"");
+        assert_eq!(
+            error("repeat 2 {\nmacro a {\n}\n}"),
+            "course.txt:2: macros can only be defined at the top level"
+        );
+        assert_eq!(
+            error("macro a {\n}\nmacro a {\n}"),
+            "course.txt:4: macro \"a\" is already defined at course.txt:1"
+        );
+        assert_eq!(
+            error("macro up {\n}"),
+            "course.txt:1:7: invalid macro name \"up\""
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Exports trajectories from `trajectory` to check a course by eye: CSV for
+//! spreadsheets, and an SVG plot of the depth profile under each model.
+
+use crate::{Model, Position};
+use std::fmt::Write;
+
+const WIDTH: f64 = 800.0;
+const PANEL_HEIGHT: f64 = 250.0;
+const MARGIN: f64 = 50.0;
+
+/// One row per position, with a header
+pub fn to_csv(positions: &[Position]) -> String {
+    let mut csv = String::from("step,horizontal,depth,aim\n");
+    for p in positions {
+        let _ = writeln!(csv, "{},{},{},{}", p.step, p.horizontal, p.depth, p.aim);
+    }
+    csv
+}
+
+/// Plots depth against horizontal position, one panel per trajectory. Each
+/// panel has its own depth scale, as the aim model dives far deeper.
+pub fn to_svg(trajectories: &[(Model, &[Position])]) -> String {
+    let height = PANEL_HEIGHT * trajectories.len() as f64;
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+        w = WIDTH,
+        h = height
+    );
+    for (i, (model, positions)) in trajectories.iter().enumerate() {
+        let top = PANEL_HEIGHT * i as f64;
+        panel(&mut svg, *model, positions, top);
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn panel(svg: &mut String, model: Model, positions: &[Position], top: f64) {
+    let max_horizontal = positions.iter().map(|p| p.horizontal).max().unwrap_or(0);
+    let min_depth = positions.iter().map(|p| p.depth).min().unwrap_or(0);
+    let max_depth = positions.iter().map(|p| p.depth).max().unwrap_or(0);
+    let (left, right) = (MARGIN, WIDTH - MARGIN / 2.0);
+    let (upper, bottom) = (top + MARGIN / 2.0, top + PANEL_HEIGHT - MARGIN);
+    // Avoid dividing by zero for courses that never move or dive
+    let x = |h: i64| left + (right - left) * h as f64 / max_horizontal.max(1) as f64;
+    // Courses that go above the surface draw it partway down
+    let (top_depth, depth_range) = (min_depth.min(0), (max_depth - min_depth.min(0)).max(1));
+    let y = |d: i64| upper + (bottom - upper) * (d - top_depth) as f64 / depth_range as f64;
+
+    let title = match model {
+        Model::Simple => "Simple model",
+        Model::Aim => "Aim model",
+    };
+    let _ = writeln!(svg, r#"  <g>"#);
+    let _ = writeln!(
+        svg,
+        r#"    <text x="{}" y="{}" text-anchor="middle">{} (depth down, horizontal across)</text>"#,
+        WIDTH / 2.0,
+        upper - 8.0,
+        title
+    );
+    let _ = writeln!(
+        svg,
+        r#"    <path d="M{left} {upper} V{bottom} H{right}" fill="none" stroke="black"/>"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"    <text x="{}" y="{}" text-anchor="end">{}</text>"#,
+        left - 4.0,
+        bottom,
+        max_depth
+    );
+    let _ = writeln!(
+        svg,
+        r#"    <text x="{}" y="{}" text-anchor="end">{}</text>"#,
+        right,
+        bottom + 16.0,
+        max_horizontal
+    );
+    if min_depth < 0 {
+        let _ = writeln!(
+            svg,
+            r#"    <path d="M{} {:.1} H{}" stroke="gray" stroke-dasharray="4"/>"#,
+            left,
+            y(0),
+            right
+        );
+    }
+    let points: Vec<String> = positions
+        .iter()
+        .map(|p| format!("{:.1},{:.1}", x(p.horizontal), y(p.depth)))
+        .collect();
+    let _ = writeln!(
+        svg,
+        r#"    <polyline points="{}" fill="none" stroke="steelblue"/>"#,
+        points.join(" ")
+    );
+    let _ = writeln!(svg, "  </g>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, trajectory};
+
+    const COURSE: &str = "forward 5\ndown 5\nforward 5";
+
+    #[test]
+    fn csv() {
+        let course = parse_input(COURSE).unwrap();
+        assert_eq!(
+            to_csv(&trajectory(&course, Model::Aim).unwrap()),
+            "step,horizontal,depth,aim\n0,0,0,0\n1,5,0,0\n2,5,0,5\n3,10,25,5\n"
+        );
+    }
+
+    #[test]
+    fn svg_has_a_panel_per_model() {
+        let course = parse_input(COURSE).unwrap();
+        let simple = trajectory(&course, Model::Simple).unwrap();
+        let aim = trajectory(&course, Model::Aim).unwrap();
+        let svg = to_svg(&[(Model::Simple, &simple), (Model::Aim, &aim)]);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        // The aim model's last point is at the bottom right of the second panel
+        assert!(svg.contains("775.0,450.0\""));
+        assert!(svg.contains("Aim model"));
+        assert!(!svg.contains("stroke-dasharray"));
+    }
+
+    #[test]
+    fn svg_marks_the_surface() {
+        let course = parse_input("up 1\nforward 1\ndown 3").unwrap();
+        let svg = to_svg(&[(Model::Simple, &trajectory(&course, Model::Simple).unwrap())]);
+        // Depths -1 to 2 span the plot, so the surface is a third of the way down
+        assert!(svg.contains(r#"<path d="M50 83.3 H775" stroke="gray""#));
+    }
+}
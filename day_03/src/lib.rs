@@ -0,0 +1,448 @@
+pub mod sorted;
+
+pub use sorted::SortedReport;
+
+use aoc_common::{Answer, Answers, Error, Params, Violation};
+use std::fmt;
+
+/// Widest numbers a report can hold
+pub const MAX_BITS: usize = 128;
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Config {
+    /// Width every number in the report must have, None to take the width of
+    /// the first line
+    pub bits: Option<usize>,
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["bits"])?;
+        // Unset means inferred from the input
+        let bits = params.get("bits", 0, 1..=MAX_BITS)?;
+        Ok(Config {
+            bits: (bits > 0).then_some(bits),
+        })
+    }
+}
+
+/// The diagnostic report: numbers of the same width
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub width: usize,
+    pub rows: Vec<u128>,
+}
+
+/// Two rates multiplied together, which can take up to 256 bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Product(pub u128, pub u128);
+
+impl Product {
+    /// The product as four 64 bit limbs, least significant first
+    fn limbs(self) -> [u64; 4] {
+        let a = [self.0 as u64, (self.0 >> 64) as u64];
+        let b = [self.1 as u64, (self.1 >> 64) as u64];
+        let mut limbs = [0; 4];
+        for i in 0..2 {
+            let mut carry = 0;
+            for j in 0..2 {
+                let sum = u128::from(a[i]) * u128::from(b[j]) + u128::from(limbs[i + j]) + carry;
+                limbs[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+            limbs[i + 2] = carry as u64;
+        }
+        limbs
+    }
+}
+
+impl fmt::Display for Product {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut limbs = self.limbs();
+        // Groups of 19 decimal digits, least significant first
+        let mut chunks = Vec::new();
+        loop {
+            let mut remainder = 0;
+            for limb in limbs.iter_mut().rev() {
+                let value = (remainder << 64) | u128::from(*limb);
+                *limb = (value / CHUNK) as u64;
+                remainder = value % CHUNK;
+            }
+            chunks.push(remainder);
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        chunks.try_for_each(|chunk| write!(f, "{:019}", chunk))
+    }
+}
+
+impl From<Product> for Answer {
+    /// An integer when it fits, as it does for reports up to 63 bits wide
+    fn from(product: Product) -> Self {
+        match product.0.checked_mul(product.1).map(i128::try_from) {
+            Some(Ok(product)) => Answer::Integer(product),
+            _ => Answer::Text(product.to_string()),
+        }
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    let sorted = parse_input_with(input, config)
+        .and_then(SortedReport::new)
+        .map_err(Error::InvalidInput)?;
+
+    Ok((
+        sorted.power_consumption().into(),
+        Some(sorted.life_support_rating().into()),
+    ))
+}
+
+/// Checks that every line is a binary number as wide as the first
+pub fn check(input: &str) -> Vec<Violation> {
+    check_with(input, &Config::default())
+}
+
+/// Like `check`, against the configured width if there is one
+pub fn check_with(input: &str, config: &Config) -> Vec<Violation> {
+    let mut width = config.bits;
+    let mut violations = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if let Err(violation) = check_line(i, line, *width.get_or_insert(line.len())) {
+            violations.push(violation);
+        }
+    }
+    violations
+}
+
+/// Parses the report, taking its width from the first line
+pub fn parse_input(contents: &str) -> Result<Report, Violation> {
+    parse_input_with(contents, &Config::default())
+}
+
+/// Like `parse_input`, against the configured width if there is one
+pub fn parse_input_with(contents: &str, config: &Config) -> Result<Report, Violation> {
+    let mut report = Report {
+        width: config.bits.unwrap_or(0),
+        rows: Vec::new(),
+    };
+    for (i, line) in contents.lines().enumerate() {
+        if i == 0 && config.bits.is_none() {
+            report.width = line.len();
+        }
+        report.rows.push(check_line(i, line, report.width)?);
+    }
+    Ok(report)
+}
+
+/// The number on the 0-based line `index`, which must be `width` bits wide
+fn check_line(index: usize, line: &str, width: usize) -> Result<u128, Violation> {
+    if let Some(column) = line.find(|c| c != '0' && c != '1') {
+        let c = line[column..].chars().next().unwrap();
+        let token = &line[column..column + c.len_utf8()];
+        return Err(Violation::token(
+            index,
+            line,
+            token,
+            "expected only 0 and 1",
+        ));
+    }
+    if line.len() > MAX_BITS {
+        let message = format!("expected at most {} bits, found {}", MAX_BITS, line.len());
+        return Err(Violation::line(index, message));
+    }
+    if line.len() != width {
+        let message = format!("expected {} bits, found {}", width, line.len());
+        return Err(Violation::line(index, message));
+    }
+    Ok(line
+        .bytes()
+        .fold(0, |number, bit| number << 1 | u128::from(bit - b'0')))
+}
+
+/// Counts set bits at given index of each number in given input
+/// ```ignore
+/// // Returns 1 if bit is set and 0 if not
+/// (num >> index) & 1
+/// ```
+fn count_set_bits_in_column(input: &[u128], index: usize) -> usize {
+    input.iter().filter(|&num| (num >> index) & 1 == 1).count()
+}
+
+/// The gamma and epsilon rates
+pub fn power_consumption(report: &Report) -> Product {
+    let (mut gamma, mut epsilon) = (0, 0);
+    for i in (0..report.width).rev() {
+        let most_common_bit = count_set_bits_in_column(&report.rows, i) * 2 >= report.rows.len();
+        gamma = gamma << 1 | u128::from(most_common_bit);
+        epsilon = epsilon << 1 | u128::from(!most_common_bit);
+    }
+    Product(gamma, epsilon)
+}
+
+/// Which bit a rating keeps the rows with at each position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Criterion {
+    MostCommon,
+    /// Least common among the remaining rows, so a bit no row has is never chosen
+    LeastCommon,
+}
+
+/// How a rating picks its bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub criterion: Criterion,
+    /// The bit kept when both are equally common
+    pub tie: bool,
+}
+
+impl Rule {
+    pub const OXYGEN_GENERATOR: Rule = Rule {
+        criterion: Criterion::MostCommon,
+        tie: true,
+    };
+    pub const CO2_SCRUBBER: Rule = Rule {
+        criterion: Criterion::LeastCommon,
+        tie: false,
+    };
+
+    /// The bit to keep at a position where the remaining rows have these counts
+    pub fn keep(self, ones: usize, zeros: usize) -> bool {
+        if ones == zeros {
+            self.tie
+        } else if ones == 0 || zeros == 0 {
+            ones > 0
+        } else {
+            match self.criterion {
+                Criterion::MostCommon => ones > zeros,
+                Criterion::LeastCommon => ones < zeros,
+            }
+        }
+    }
+}
+
+/// What a rating did at one bit position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// 0 for the most significant bit
+    pub position: usize,
+    pub ones: usize,
+    pub zeros: usize,
+    pub kept: bool,
+    /// Rows left after keeping only those with the `kept` bit
+    pub remaining: usize,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "bit {}: {} ones and {} zeros, keeping rows with {}, {} left",
+            self.position + 1,
+            self.ones,
+            self.zeros,
+            u8::from(self.kept),
+            self.remaining
+        )
+    }
+}
+
+/// Filters the rows bit by bit from the most significant, keeping the rows
+/// with the bit `rule` picks, until one row is left. None for an empty report.
+/// Rows still left after the last bit are all equal.
+pub fn rating(report: &Report, rule: Rule) -> Option<u128> {
+    filter(report, rule, None)
+}
+
+/// Like `rating`, also returning what happened at each bit it looked at
+pub fn rating_with_trace(report: &Report, rule: Rule) -> (Option<u128>, Vec<Step>) {
+    let mut trace = Vec::new();
+    let rating = filter(report, rule, Some(&mut trace));
+    (rating, trace)
+}
+
+fn filter(report: &Report, rule: Rule, mut trace: Option<&mut Vec<Step>>) -> Option<u128> {
+    let mut selected_rows = report.rows.clone();
+    for (position, i) in (0..report.width).rev().enumerate() {
+        if selected_rows.len() <= 1 {
+            break;
+        }
+        let ones = count_set_bits_in_column(&selected_rows, i);
+        let zeros = selected_rows.len() - ones;
+        let kept = rule.keep(ones, zeros);
+
+        selected_rows.retain(|num| (num >> i) & 1 == u128::from(kept));
+
+        if let Some(trace) = trace.as_deref_mut() {
+            trace.push(Step {
+                position,
+                ones,
+                zeros,
+                kept,
+                remaining: selected_rows.len(),
+            });
+        }
+    }
+    selected_rows.first().copied()
+}
+
+/// The oxygen generator and CO2 scrubber ratings, 0 for an empty report
+pub fn life_support_rating(report: &Report) -> Product {
+    let rating = |rule| rating(report, rule).unwrap_or(0);
+    Product(rating(Rule::OXYGEN_GENERATOR), rating(Rule::CO2_SCRUBBER))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn five_bit_example() {
+        assert_eq!(check(EXAMPLE), []);
+        let report = parse_input(EXAMPLE).unwrap();
+        assert_eq!(report.width, 5);
+        assert_eq!(power_consumption(&report), Product(22, 9));
+        assert_eq!(life_support_rating(&report), Product(23, 10));
+        assert_eq!(solve(EXAMPLE), Ok((198u32.into(), Some(230u32.into()))));
+    }
+
+    #[test]
+    fn configured_width() {
+        let config = Config { bits: Some(6) };
+        assert_eq!(check_with(EXAMPLE, &config).len(), 12);
+        assert_eq!(
+            solve_with(EXAMPLE, &config),
+            Err(Error::InvalidInput(Violation::line(
+                0,
+                "expected 6 bits, found 5"
+            )))
+        );
+        let mut params = Params::new();
+        assert_eq!(Config::from_params(&params), Ok(Config::default()));
+        params.set("bits", 5);
+        assert_eq!(Config::from_params(&params), Ok(Config { bits: Some(5) }));
+        params.set("bits", 129);
+        assert!(Config::from_params(&params).is_err());
+    }
+
+    #[test]
+    fn ragged_lines() {
+        let input = "0101\n011\n0121\n01010\n0é01";
+        let violations: Vec<String> = check(input).iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            violations,
+            [
+                "2: expected 4 bits, found 3",
+                "3:3: expected only 0 and 1",
+                "4: expected 4 bits, found 5",
+                "5:2: expected only 0 and 1"
+            ]
+        );
+        assert_eq!(
+            parse_input(input).unwrap_err().to_string(),
+            "2: expected 4 bits, found 3"
+        );
+    }
+
+    #[test]
+    fn wide_reports() {
+        // The 5 bit example with 123 leading ones on every row
+        let wide: String = EXAMPLE
+            .lines()
+            .map(|row| "1".repeat(123) + row + "\n")
+            .collect();
+        let report = parse_input(&wide).unwrap();
+        assert_eq!(report.width, MAX_BITS);
+        let ones = u128::MAX << 5;
+        assert_eq!(power_consumption(&report), Product(ones | 22, 9));
+        assert_eq!(life_support_rating(&report), Product(ones | 23, ones | 10));
+
+        let too_wide = "1".repeat(MAX_BITS + 1);
+        assert_eq!(
+            parse_input(&too_wide).unwrap_err().to_string(),
+            "1: expected at most 128 bits, found 129"
+        );
+    }
+
+    #[test]
+    fn products_beyond_128_bits() {
+        assert_eq!(Answer::from(Product(22, 9)), Answer::Integer(198));
+        assert_eq!(Product(0, u128::MAX).to_string(), "0");
+        assert_eq!(
+            Product(u128::MAX, u128::MAX).to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert_eq!(
+            Answer::from(Product(3 << 64, 1 << 64)),
+            Answer::Text("1020847100762815390390123822295304634368".to_string())
+        );
+    }
+
+    #[test]
+    fn trace() {
+        let report = parse_input(EXAMPLE).unwrap();
+        let (co2, trace) = rating_with_trace(&report, Rule::CO2_SCRUBBER);
+        assert_eq!(co2, Some(0b01010));
+        let steps: Vec<String> = trace.iter().map(Step::to_string).collect();
+        assert_eq!(
+            steps,
+            [
+                "bit 1: 7 ones and 5 zeros, keeping rows with 0, 5 left",
+                "bit 2: 2 ones and 3 zeros, keeping rows with 1, 2 left",
+                "bit 3: 1 ones and 1 zeros, keeping rows with 0, 1 left",
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        let report = parse_input(EXAMPLE).unwrap();
+        let rule = |criterion, tie| Rule { criterion, tie };
+        // Each rating meets one tie, at its last bit
+        assert_eq!(
+            rating(&report, rule(Criterion::MostCommon, false)),
+            Some(0b10110)
+        );
+        assert_eq!(
+            rating(&report, rule(Criterion::LeastCommon, true)),
+            Some(0b01111)
+        );
+        assert_eq!(rating(&Report::default(), Rule::OXYGEN_GENERATOR), None);
+    }
+
+    #[test]
+    fn filtering_never_removes_every_row() {
+        // The puzzle's rule would keep the absent 0 at the first bit
+        let report = parse_input("11\n11\n10").unwrap();
+        let (co2, trace) = rating_with_trace(&report, Rule::CO2_SCRUBBER);
+        assert_eq!(co2, Some(0b10));
+        assert_eq!(trace[0].remaining, 3);
+        // Duplicates agree on every bit, so they rate the same
+        let report = parse_input("01\n01").unwrap();
+        assert_eq!(rating(&report, Rule::CO2_SCRUBBER), Some(0b01));
+    }
+}
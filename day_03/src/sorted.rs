@@ -0,0 +1,224 @@
+//! The report with its rows sorted, for reports with millions of rows.
+//!
+//! Rows sharing their leading bits are next to each other once sorted, and
+//! the rows among them with a 0 at the next bit come before those with a 1.
+//! So the rows a rating keeps are always a range, which each bit splits in
+//! two with a binary search instead of a pass over the rows. Sorting is a
+//! radix sort, so preparing the report is linear in its size, as is counting
+//! the bits of every column for the power consumption.
+
+use crate::{Product, Report, Rule, Step, MAX_BITS};
+use aoc_common::Violation;
+
+pub struct SortedReport {
+    width: usize,
+    rows: Vec<u128>,
+    /// Set bits in each column, indexed from the least significant
+    ones: Vec<usize>,
+}
+
+impl SortedReport {
+    /// Fails on the first row wider than the report, as `parse_input` does
+    pub fn new(report: Report) -> Result<Self, Violation> {
+        let Report { width, mut rows } = report;
+        if width > MAX_BITS {
+            let message = format!("expected at most {} bits, found {}", MAX_BITS, width);
+            return Err(Violation::line(0, message));
+        }
+        let mut ones = vec![0; width];
+        for (i, &row) in rows.iter().enumerate() {
+            let row_width = (u128::BITS - row.leading_zeros()) as usize;
+            if row_width > width {
+                let message = format!("expected {} bits, found {}", width, row_width);
+                return Err(Violation::line(i, message));
+            }
+            let mut bits = row;
+            while bits != 0 {
+                ones[bits.trailing_zeros() as usize] += 1;
+                bits &= bits - 1;
+            }
+        }
+        radix_sort(&mut rows, width);
+        Ok(SortedReport { width, rows, ones })
+    }
+
+    /// Bits in every row
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The gamma and epsilon rates, as `power_consumption` computes them
+    pub fn power_consumption(&self) -> Product {
+        let (mut gamma, mut epsilon) = (0, 0);
+        for &ones in self.ones.iter().rev() {
+            let most_common_bit = ones * 2 >= self.rows.len();
+            gamma = gamma << 1 | u128::from(most_common_bit);
+            epsilon = epsilon << 1 | u128::from(!most_common_bit);
+        }
+        Product(gamma, epsilon)
+    }
+
+    /// The same rating as `rating`
+    pub fn rating(&self, rule: Rule) -> Option<u128> {
+        self.filter(rule, None)
+    }
+
+    /// The same rating and trace as `rating_with_trace`
+    pub fn rating_with_trace(&self, rule: Rule) -> (Option<u128>, Vec<Step>) {
+        let mut trace = Vec::new();
+        let rating = self.filter(rule, Some(&mut trace));
+        (rating, trace)
+    }
+
+    /// The oxygen generator and CO2 scrubber ratings, 0 for an empty report
+    pub fn life_support_rating(&self) -> Product {
+        let rating = |rule| self.rating(rule).unwrap_or(0);
+        Product(rating(Rule::OXYGEN_GENERATOR), rating(Rule::CO2_SCRUBBER))
+    }
+
+    fn filter(&self, rule: Rule, mut trace: Option<&mut Vec<Step>>) -> Option<u128> {
+        let (mut start, mut end) = (0, self.rows.len());
+        for (position, i) in (0..self.width).rev().enumerate() {
+            if end - start <= 1 {
+                break;
+            }
+            let split = start + self.rows[start..end].partition_point(|row| (row >> i) & 1 == 0);
+            let (zeros, ones) = (split - start, end - split);
+            let kept = rule.keep(ones, zeros);
+            if kept {
+                start = split;
+            } else {
+                end = split;
+            }
+
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(Step {
+                    position,
+                    ones,
+                    zeros,
+                    kept,
+                    remaining: end - start,
+                });
+            }
+        }
+        self.rows.get(start..end)?.first().copied()
+    }
+}
+
+/// Sorts numbers of `width` bits a byte at a time, from the least significant
+fn radix_sort(rows: &mut Vec<u128>, width: usize) {
+    let mut sorted = vec![0; rows.len()];
+    for shift in (0..width).step_by(8) {
+        let digit = |row: u128| (row >> shift) as usize & 0xff;
+        let mut starts = [0; 256];
+        for &row in rows.iter() {
+            starts[digit(row)] += 1;
+        }
+        let mut total = 0;
+        for start in &mut starts {
+            (*start, total) = (total, total + *start);
+        }
+        for &row in rows.iter() {
+            let start = &mut starts[digit(row)];
+            sorted[*start] = row;
+            *start += 1;
+        }
+        std::mem::swap(rows, &mut sorted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        life_support_rating, parse_input, power_consumption, rating_with_trace, Criterion,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn example() {
+        let example =
+            "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+        let sorted = SortedReport::new(parse_input(example).unwrap()).unwrap();
+        assert_eq!(sorted.power_consumption(), Product(22, 9));
+        assert_eq!(sorted.life_support_rating(), Product(23, 10));
+        assert_eq!(
+            SortedReport::new(Report::default())
+                .unwrap()
+                .life_support_rating(),
+            Product(0, 0)
+        );
+    }
+
+    #[test]
+    fn rows_wider_than_the_report() {
+        let report = Report {
+            width: 3,
+            rows: vec![0b101, 0b1000],
+        };
+        assert_eq!(
+            SortedReport::new(report).err().map(|v| v.to_string()),
+            Some("2: expected 3 bits, found 4".to_string())
+        );
+        let report = Report {
+            width: 129,
+            rows: vec![],
+        };
+        assert!(SortedReport::new(report).is_err());
+    }
+
+    #[test]
+    fn radix_sort_wide_rows() {
+        let mut rows = vec![u128::MAX, 1 << 127, 0, 1 << 64, 255, 256];
+        let mut expected = rows.clone();
+        expected.sort_unstable();
+        radix_sort(&mut rows, 128);
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn millions_of_rows() {
+        // A linear congruential generator, so the report is the same every run
+        let mut state = 2021u64;
+        let rows = (0..2_000_000)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                u128::from(state >> 40)
+            })
+            .collect();
+        let report = Report { width: 24, rows };
+        let sorted = SortedReport::new(report.clone()).unwrap();
+        assert_eq!(sorted.power_consumption(), power_consumption(&report));
+        assert_eq!(sorted.life_support_rating(), life_support_rating(&report));
+    }
+
+    fn report() -> impl Strategy<Value = Report> {
+        (1..=128usize).prop_flat_map(|width| {
+            prop::collection::vec(
+                any::<u128>().prop_map(move |row| row >> (128 - width)),
+                0..200,
+            )
+            .prop_map(move |rows| Report { width, rows })
+        })
+    }
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        (any::<bool>(), any::<bool>()).prop_map(|(most_common, tie)| Rule {
+            criterion: if most_common {
+                Criterion::MostCommon
+            } else {
+                Criterion::LeastCommon
+            },
+            tie,
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn matches_filtering(report in report(), rule in rule()) {
+            let sorted = SortedReport::new(report.clone()).unwrap();
+            prop_assert_eq!(sorted.power_consumption(), power_consumption(&report));
+            prop_assert_eq!(sorted.rating_with_trace(rule), rating_with_trace(&report, rule));
+        }
+    }
+}
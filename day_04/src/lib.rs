@@ -0,0 +1,246 @@
+use aoc_common::{budget, Answers, Error, Violation};
+
+/// Used to mark drawn numbers, all board numbers are < 100.
+const DRAWN: u32 = 100;
+pub type Board = [[u32; 5]; 5];
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let (selection, boards) = parse_input(input);
+
+    Ok((
+        bingo_first_winner(&selection, &boards)?.into(),
+        Some(bingo_last_winner(&selection, &boards)?.into()),
+    ))
+}
+
+/// Checks that the drawn numbers and the boards of 5 rows of 5 numbers are all below `DRAWN`
+pub fn check(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut check_number = |i, line, number: &str| match number.parse::<u32>() {
+        Ok(n) if n < DRAWN => {}
+        Ok(n) => violations.push(Violation::token(
+            i,
+            line,
+            number,
+            format!("{} is not below {}", n, DRAWN),
+        )),
+        Err(_) => violations.push(Violation::token(
+            i,
+            line,
+            number,
+            format!("{:?} is not a number", number),
+        )),
+    };
+
+    let mut lines = input.lines().enumerate();
+    if let Some((i, line)) = lines.next() {
+        line.split(',')
+            .for_each(|number| check_number(i, line, number));
+    }
+
+    let mut rows: usize = 0;
+    let mut row_lengths = Vec::new();
+    for (i, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let numbers: Vec<&str> = line.split_whitespace().collect();
+        numbers
+            .iter()
+            .for_each(|number| check_number(i, line, number));
+        if numbers.len() != 5 {
+            row_lengths.push(Violation::line(
+                i,
+                format!("expected 5 numbers in a board row, found {}", numbers.len()),
+            ));
+        }
+        rows += 1;
+    }
+    violations.extend(row_lengths);
+    if !rows.is_multiple_of(5) {
+        violations.push(Violation::line(
+            input.lines().count() - 1,
+            format!("the last board has {} rows instead of 5", rows % 5),
+        ));
+    }
+    violations.sort_by_key(|v| (v.line, v.column));
+    violations
+}
+
+pub fn parse_input(contents: &str) -> (Vec<u32>, Vec<Board>) {
+    let mut lines = contents.lines();
+    let selection: Vec<u32> = lines
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect();
+
+    let mut board: Board = [[0; 5]; 5];
+    let mut i = 0;
+    let boards = lines.fold(Vec::new(), |mut boards, line| {
+        if !line.is_empty() {
+            line.split_whitespace()
+                .enumerate()
+                .for_each(|(j, num)| board[i][j] = num.parse().unwrap());
+            i += 1;
+        }
+        if i == 5 {
+            boards.push(board);
+            board = [[0; 5]; 5];
+            i = 0;
+        }
+        boards
+    });
+    (selection, boards)
+}
+
+fn check_column(board: &Board, index: usize) -> bool {
+    let column = board
+        .iter()
+        .enumerate()
+        .fold([0; 5], |mut column, (i, row)| {
+            column[i] = row[index];
+            column
+        });
+    column.iter().all(|&num| num == DRAWN)
+}
+
+pub fn bingo_first_winner(selection: &[u32], boards: &[Board]) -> Result<u32, Error> {
+    let mut boards = boards.to_vec();
+
+    let winning_board_idx: usize;
+
+    let mut idx = 0;
+    'outer: loop {
+        budget::tick("bingo_first_winner")?;
+        let number = *selection.get(idx).ok_or(Error::NoWinner)?;
+
+        for (i, board) in boards.iter_mut().enumerate() {
+            for j in 0..5 {
+                for k in 0..5 {
+                    if board[j][k] == number {
+                        board[j][k] = DRAWN;
+                        if board[j].iter().all(|&num| num == DRAWN) || check_column(board, k) {
+                            // Bingo
+                            winning_board_idx = i;
+                            break 'outer;
+                        }
+                    }
+                }
+            }
+        }
+        idx += 1;
+    }
+
+    let sum_undrawn: u32 = boards[winning_board_idx]
+        .into_iter()
+        .flatten()
+        .filter(|&n| n != DRAWN)
+        .sum();
+
+    Ok(selection[idx] * sum_undrawn)
+}
+
+pub fn bingo_last_winner(selection: &[u32], boards: &[Board]) -> Result<u32, Error> {
+    let mut boards = boards.to_vec();
+    let mut last_winning_board = *boards.first().ok_or(Error::NoWinner)?;
+
+    let mut idx = 0;
+    loop {
+        budget::tick("bingo_last_winner")?;
+        let number = *selection.get(idx).ok_or(Error::NoWinner)?;
+
+        boards.retain_mut(|board| {
+            for i in 0..5 {
+                for j in 0..5 {
+                    if board[i][j] == number {
+                        board[i][j] = DRAWN;
+                        if board[i].iter().all(|&num| num == DRAWN) || check_column(board, j) {
+                            // Bingo, drop board
+                            last_winning_board = *board;
+                            return false;
+                        }
+                    }
+                }
+            }
+            // Not bingo, keep board
+            true
+        });
+
+        if boards.is_empty() {
+            break;
+        }
+        idx += 1;
+    }
+
+    let sum_undrawn: u32 = last_winning_board
+        .into_iter()
+        .flatten()
+        .filter(|&n| n != DRAWN)
+        .sum();
+
+    Ok(selection[idx] * (sum_undrawn))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn part_one() {
+        let (selection, boards) = parse_input(EXAMPLE);
+        assert_eq!(bingo_first_winner(&selection, &boards), Ok(4512));
+    }
+
+    #[test]
+    fn part_two() {
+        let (selection, boards) = parse_input(EXAMPLE);
+        assert_eq!(bingo_last_winner(&selection, &boards), Ok(1924));
+    }
+
+    #[test]
+    fn no_winner() {
+        let (_, boards) = parse_input(EXAMPLE);
+        assert_eq!(bingo_first_winner(&[7, 4], &boards), Err(Error::NoWinner));
+    }
+
+    #[test]
+    fn column_complete() {
+        let mut board = parse_input(EXAMPLE).1[0];
+        for row in board.iter_mut().take(4) {
+            row[2] = DRAWN;
+        }
+        assert!(!check_column(&board, 2));
+        board[4][2] = DRAWN;
+        assert!(check_column(&board, 2));
+        assert!(!check_column(&board, 3));
+    }
+
+    #[test]
+    fn check_example() {
+        assert_eq!(check(EXAMPLE), []);
+    }
+}
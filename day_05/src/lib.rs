@@ -0,0 +1,153 @@
+use aoc_common::{Answers, Error, Violation};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        find_overlaps(&parsed_input, false).into(),
+        Some(find_overlaps(&parsed_input, true).into()),
+    ))
+}
+
+/// Every coordinate is below this
+pub const GRID_SIZE: usize = 1000;
+
+#[derive(Debug)]
+pub struct Line {
+    /// (x, y)
+    start: (usize, usize),
+    /// (x, y)
+    end: (usize, usize),
+}
+
+/// Checks that every line is `x1,y1 -> x2,y2` within the grid, and is horizontal,
+/// vertical or diagonal at 45 degrees
+pub fn check(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let Some((start, end)) = line.split_once(" -> ") else {
+            violations.push(Violation::line(i, "expected `x1,y1 -> x2,y2`"));
+            continue;
+        };
+
+        let mut coordinates = Vec::new();
+        for point in [start, end] {
+            let Some((x, y)) = point.split_once(',') else {
+                violations.push(Violation::token(i, line, point, "expected `x,y`"));
+                continue;
+            };
+            for coordinate in [x, y] {
+                match coordinate.parse::<usize>() {
+                    Ok(n) if n < GRID_SIZE => coordinates.push(n),
+                    Ok(n) => violations.push(Violation::token(
+                        i,
+                        line,
+                        coordinate,
+                        format!("{} is not below {}", n, GRID_SIZE),
+                    )),
+                    Err(_) => violations.push(Violation::token(
+                        i,
+                        line,
+                        coordinate,
+                        format!("{:?} is not a number", coordinate),
+                    )),
+                }
+            }
+        }
+
+        if let [x1, y1, x2, y2] = coordinates[..] {
+            if x1 != x2 && y1 != y2 && x1.abs_diff(x2) != y1.abs_diff(y2) {
+                violations.push(Violation::line(
+                    i,
+                    "the line is not horizontal, vertical or diagonal at 45 degrees",
+                ));
+            }
+        }
+    }
+    violations
+}
+
+pub fn parse_input(contents: &str) -> Vec<Line> {
+    contents
+        .lines()
+        .map(|line| line.replace(" -> ", " "))
+        .map(|line| line.replace(',', " "))
+        .fold(Vec::new(), |mut coords, line| {
+            coords.push({
+                let line: Vec<usize> = line.split(' ').map(|s| s.parse().unwrap()).collect();
+
+                Line {
+                    start: (line[0], line[1]),
+                    end: (line[2], line[3]),
+                }
+            });
+            coords
+        })
+}
+
+pub fn find_overlaps(lines: &[Line], include_diagonal_lines: bool) -> usize {
+    let mut grid = vec![vec![0u8; GRID_SIZE]; GRID_SIZE];
+
+    lines.iter().for_each(|line| {
+        if line.start.0 == line.end.0 {
+            let mut y = [line.start.1, line.end.1];
+            y.sort_unstable();
+            for row in &mut grid[y[0]..=y[1]] {
+                row[line.start.0] += 1;
+            }
+        } else if line.start.1 == line.end.1 {
+            let mut x = [line.start.0, line.end.0];
+            x.sort_unstable();
+            for cell in &mut grid[line.start.1][x[0]..=x[1]] {
+                *cell += 1;
+            }
+        } else if include_diagonal_lines {
+            let x: Vec<usize> = if line.start.0 > line.end.0 {
+                (line.end.0..=line.start.0).rev().collect()
+            } else {
+                (line.start.0..=line.end.0).collect()
+            };
+
+            let y: Vec<usize> = if line.start.1 > line.end.1 {
+                (line.end.1..=line.start.1).rev().collect()
+            } else {
+                (line.start.1..=line.end.1).collect()
+            };
+
+            for i in 0..x.len() {
+                grid[y[i]][x[i]] += 1;
+            }
+        }
+    });
+    grid.into_iter()
+        .map(|row| row.into_iter().filter(|&x| x > 1).count())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(find_overlaps(&parse_input(EXAMPLE), false), 5);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(find_overlaps(&parse_input(EXAMPLE), true), 12);
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::{Answers, Error, Params};
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Days simulated for part one
+    pub part_one_days: u16,
+    /// Days simulated for part two
+    pub part_two_days: u16,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_days: 80,
+            part_two_days: 256,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["part_one_days", "part_two_days"])?;
+        let default = Config::default();
+        Ok(Config {
+            part_one_days: params.get("part_one_days", default.part_one_days, 0..)?,
+            part_two_days: params.get("part_two_days", default.part_two_days, 0..)?,
+        })
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        simulate_lanternfish(&parsed_input, config.part_one_days)?.into(),
+        Some(simulate_lanternfish(&parsed_input, config.part_two_days)?.into()),
+    ))
+}
+
+pub fn parse_input(contents: &str) -> Vec<usize> {
+    contents.split(',').map(|n| n.parse().unwrap()).collect()
+}
+
+/// The number of fish after `days`, failing once there are too many to count in a `u64`
+pub fn simulate_lanternfish(seed: &[usize], days: u16) -> Result<u64, Error> {
+    let overflow = |day| Error::Overflow(format!("the school outgrows a u64 by day {}", day));
+    // Track the number of fish in each state
+    let mut fish = [0u64; 9];
+
+    seed.iter().for_each(|&state| fish[state] += 1);
+
+    let mut day = 0;
+    // each day the fish in one state shift right to the next state,
+    // spawning new fish when state is 0
+    while day < days {
+        let will_spawn = fish[0];
+        for i in 0..fish.len() {
+            if i < 8 {
+                fish[i] = fish[i + 1];
+            }
+        }
+        // fish with state 0 create a new fish with state 8
+        fish[8] = will_spawn;
+        // fish with state 0 becomes a fish with state 6
+        day += 1;
+        fish[6] = fish[6]
+            .checked_add(will_spawn)
+            .ok_or_else(|| overflow(day))?;
+    }
+
+    fish.iter()
+        .try_fold(0u64, |total, &count| total.checked_add(count))
+        .ok_or_else(|| overflow(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+3,4,3,1,2";
+
+    #[test]
+    fn part_one() {
+        let seed = parse_input(EXAMPLE);
+        assert_eq!(simulate_lanternfish(&seed, 18), Ok(26));
+        assert_eq!(simulate_lanternfish(&seed, 80), Ok(5934));
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(
+            simulate_lanternfish(&parse_input(EXAMPLE), 256),
+            Ok(26984457539)
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let seed = parse_input(EXAMPLE);
+        assert!(simulate_lanternfish(&seed, 400).is_ok());
+        assert_eq!(
+            simulate_lanternfish(&seed, 1000).unwrap_err().to_string(),
+            "overflow: the school outgrows a u64 by day 512"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn population_never_shrinks(
+            seed in prop::collection::vec(0..=8usize, 1..300),
+            days in 0..256u16,
+        ) {
+            let before = simulate_lanternfish(&seed, days).unwrap();
+            prop_assert!(simulate_lanternfish(&seed, days + 1).unwrap() >= before);
+        }
+    }
+}
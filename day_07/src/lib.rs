@@ -0,0 +1,72 @@
+use aoc_common::{budget, Answers, Error};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        part_one(&parsed_input).into(),
+        Some(part_two(&parsed_input)?.into()),
+    ))
+}
+
+pub fn parse_input(input: &str) -> Vec<i32> {
+    let mut positions: Vec<i32> = input.split(',').map(|n| n.parse().unwrap()).collect();
+    positions.sort_unstable();
+    positions
+}
+
+/// The optimal position the crabs can align to is the median of the sorted positions
+pub fn part_one(positions: &[i32]) -> i32 {
+    let target = positions[positions.len() / 2];
+
+    positions.iter().map(|crab| (crab - target).abs()).sum()
+}
+
+pub fn part_two(positions: &[i32]) -> Result<i32, Error> {
+    // Start calculating from the middle to reduce iterations
+    let median: i32 = positions[positions.len() / 2];
+
+    let mut current_cost = calc_fuel_cost(positions, median);
+
+    let mut i = 0;
+    // Loop until the fuel cost of the next position is greater
+    loop {
+        budget::tick("part_two")?;
+        if calc_fuel_cost(positions, median + i) > current_cost {
+            break Ok(current_cost);
+        }
+        current_cost = calc_fuel_cost(positions, median + i);
+        i += 1;
+    }
+}
+
+fn calc_fuel_cost(positions: &[i32], target: i32) -> i32 {
+    positions
+        .iter()
+        .map(|crab| triangle((crab - target).abs()))
+        .sum()
+}
+
+/// Use triangular number formula to calculate fuel cost for part two
+fn triangle(n: i32) -> i32 {
+    (n * (n + 1)) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(super::part_one(&parse_input(EXAMPLE)), 37);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(super::part_two(&parse_input(EXAMPLE)), Ok(168));
+    }
+}
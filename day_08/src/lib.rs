@@ -0,0 +1,174 @@
+use aoc_common::{Answers, Error};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        part_one(&parsed_input).into(),
+        Some(part_two(&parsed_input).into()),
+    ))
+}
+
+pub struct Patterns<'a> {
+    signal: Vec<&'a str>,
+    output: Vec<&'a str>,
+}
+
+pub fn parse_input(input: &str) -> Vec<Patterns<'_>> {
+    input.lines().fold(Vec::new(), |mut patterns, line| {
+        let p = line.split_once(" | ").unwrap();
+        patterns.push(Patterns {
+            signal: p.0.split(' ').collect(),
+            output: p.1.split(' ').collect(),
+        });
+        patterns
+    })
+}
+
+/// The digits 1, 4, 7 and 8 can be decoded simply by segment length
+pub fn part_one(input: &[Patterns]) -> u32 {
+    input.iter().fold(0, |mut total, pattern| {
+        total += pattern.output.iter().fold(0, |mut count, segment| {
+            match segment.len() {
+                2 | 3 | 4 | 7 => count += 1,
+                _ => (),
+            };
+            count
+        });
+
+        total
+    })
+}
+
+/// Use the patterns of the easily decoded segments to decode the rest
+pub fn part_two(patterns: &[Patterns]) -> u32 {
+    patterns.iter().fold(0, |mut sum, pattern| {
+        let mut known = Known {
+            one: "",
+            four: "",
+            seven: "",
+        };
+
+        pattern.signal.iter().for_each(|signal| match signal.len() {
+            2 => known.one = signal,
+            3 => known.seven = signal,
+            4 => known.four = signal,
+            _ => (),
+        });
+
+        sum += pattern
+            .output
+            .iter()
+            .fold(String::new(), |mut num, code| {
+                match code.len() {
+                    2 => num.push('1'),
+                    3 => num.push('7'),
+                    4 => num.push('4'),
+                    5 => num.push(known.decode(code)),
+                    6 => num.push(known.decode(code)),
+                    7 => num.push('8'),
+                    _ => (),
+                }
+                num
+            })
+            .parse::<u32>()
+            .unwrap();
+        sum
+    })
+}
+
+struct Known<'a> {
+    one: &'a str,
+    four: &'a str,
+    seven: &'a str,
+}
+impl<'a> Known<'a> {
+    /// Use the known digits to decode the rest
+    fn decode(&self, code: &str) -> char {
+        let mut matches = 0;
+        if code.len() == 5 {
+            for c in code.chars() {
+                if self.seven.contains(c) {
+                    matches += 1;
+                }
+            }
+            if matches == 3 {
+                return '3';
+            }
+            matches = 0;
+            for c in code.chars() {
+                if self.four.contains(c) {
+                    matches += 1;
+                }
+            }
+            if matches == 3 {
+                '5'
+            } else {
+                '2'
+            }
+        } else {
+            for c in code.chars() {
+                if self.one.contains(c) {
+                    matches += 1;
+                }
+            }
+            if matches == 1 {
+                return '6';
+            }
+            matches = 0;
+            for c in code.chars() {
+                if self.four.contains(c) {
+                    matches += 1;
+                }
+            }
+            if matches == 4 {
+                '9'
+            } else {
+                '0'
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(super::part_one(&parse_input(EXAMPLE)), 26);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(super::part_two(&parse_input(EXAMPLE)), 61229);
+    }
+
+    #[test]
+    fn decode() {
+        // The wiring from the puzzle's single entry example
+        let known = Known {
+            one: "ab",
+            four: "eafb",
+            seven: "dab",
+        };
+        let decoded: String = ["cdfbe", "gcdfa", "fbcad", "cefabd", "cdfgeb", "cagedb"]
+            .iter()
+            .map(|code| known.decode(code))
+            .collect();
+        assert_eq!(decoded, "523960");
+    }
+}
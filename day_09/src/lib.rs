@@ -0,0 +1,180 @@
+use aoc_common::{Answers, Error};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+
+    Ok((
+        part_one(&parsed_input).into(),
+        Some(part_two(&parsed_input).into()),
+    ))
+}
+
+use std::collections::HashSet;
+
+pub fn parse_input(input: &str) -> Vec<Vec<Point>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(j, char)| Point {
+                    x: i,
+                    y: j,
+                    value: char.to_digit(10).unwrap(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A point on the heightmap of the cave
+#[derive(Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Point {
+    x: usize,
+    y: usize,
+    value: u32,
+}
+
+/// The sum of the risk levels of all low points on the map
+pub fn part_one(input: &[Vec<Point>]) -> u32 {
+    let mut total = 0;
+    for row in input {
+        for point in row {
+            let lowest_neighbor = find_neighbors(point, input).into_iter().min().unwrap();
+
+            if point.value < lowest_neighbor {
+                total += point.value + 1
+            }
+        }
+    }
+    total
+}
+
+/// Find the product of the three biggest basins
+pub fn part_two(input: &[Vec<Point>]) -> usize {
+    let mut lowest_points: Vec<HashSet<Point>> = Vec::new();
+    for row in input {
+        for point in row {
+            let lowest_neighbor = find_neighbors(point, input).into_iter().min().unwrap();
+
+            if point.value < lowest_neighbor {
+                lowest_points.push(HashSet::from([*point]))
+            }
+        }
+    }
+
+    // Search for higher neighbors until there are none, collect basin sizes
+    let mut basin_sizes: Vec<usize> = lowest_points.iter().fold(
+        Vec::with_capacity(lowest_points.len()),
+        |mut basins, basin| {
+            let mut current_basin = search_higher(basin, input);
+
+            basins.push(loop {
+                if search_higher(&current_basin, input).len() == current_basin.len() {
+                    // no higher neighbors, return basin size
+                    break current_basin.len();
+                } else {
+                    current_basin = search_higher(&current_basin, input);
+                }
+            });
+
+            basins
+        },
+    );
+
+    // return product of three biggest basins
+    basin_sizes.sort_unstable();
+    basin_sizes[basin_sizes.len() - 3..].iter().product()
+}
+
+fn find_neighbors(p: &Point, grid: &[Vec<Point>]) -> Vec<u32> {
+    let mut neighbor_values = Vec::with_capacity(4);
+    //top
+    if p.x > 0 {
+        neighbor_values.push(grid[p.x - 1][p.y].value);
+    }
+    //left
+    if p.y > 0 {
+        neighbor_values.push(grid[p.x][p.y - 1].value);
+    }
+    //right
+    if p.y + 1 < grid[p.x].len() {
+        neighbor_values.push(grid[p.x][p.y + 1].value);
+    }
+    //bottom
+    if p.x + 1 < grid.len() {
+        neighbor_values.push(grid[p.x + 1][p.y].value);
+    }
+    neighbor_values
+}
+
+fn search_higher(basin: &HashSet<Point>, grid: &[Vec<Point>]) -> HashSet<Point> {
+    let mut expanded_basin = basin.clone();
+    for point in basin {
+        for neighbor in find_higher_neighbors(point.x, point.y, grid) {
+            expanded_basin.insert(neighbor);
+        }
+    }
+    expanded_basin
+}
+
+fn find_higher_neighbors(row: usize, col: usize, grid: &[Vec<Point>]) -> Vec<Point> {
+    let mut basin: Vec<Point> = Vec::with_capacity(4);
+    //top
+    if row > 0 {
+        let mut i: usize = 1;
+        while row >= i && grid[row - i][col].value < 9 {
+            basin.push(grid[row - i][col]);
+            i += 1;
+        }
+    }
+    //left
+    if col > 0 {
+        let mut i: usize = 1;
+        while col >= i && grid[row][col - i].value < 9 {
+            basin.push(grid[row][col - i]);
+            i += 1;
+        }
+    }
+    //right
+    if col + 1 < grid[row].len() {
+        let mut i: usize = 1;
+        while col + i < grid[row].len() && grid[row][col + i].value < 9 {
+            basin.push(grid[row][col + i]);
+            i += 1;
+        }
+    }
+    //bottom
+    if row + 1 < grid.len() {
+        let mut i: usize = 1;
+        while row + i < grid.len() && grid[row + i][col].value < 9 {
+            basin.push(grid[row + i][col]);
+            i += 1;
+        }
+    }
+    basin
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(super::part_one(&parse_input(EXAMPLE)), 15);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(super::part_two(&parse_input(EXAMPLE)), 1134);
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_common::{Answers, Error};
+
+const OPEN: [char; 4] = ['(', '[', '{', '<'];
+const CLOSE: [char; 4] = [')', ']', '}', '>'];
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let (score, incomplete) = part_one(&parse_input(input));
+
+    Ok((score.into(), Some(part_two(&incomplete).into())))
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<char>> {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Returns part one score and incomplete lines for part two
+pub fn part_one(input: &[Vec<char>]) -> (u32, Vec<Vec<char>>) {
+    let mut incomplete = Vec::new();
+    let score = input.iter().fold(0, |mut total, line| {
+        let mut i = 0;
+        let mut stack: Vec<char> = vec![line[i]];
+        loop {
+            let close_idx = CLOSE.iter().position(|c| *c == line[i + 1]);
+            // A closing character with nothing left open is corrupted too
+            let open_idx = OPEN.iter().position(|c| Some(c) == stack.last());
+
+            if close_idx.is_none() {
+                stack.push(line[i + 1]);
+            } else if close_idx == open_idx {
+                stack.pop();
+            } else {
+                total += match line[i + 1] {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    '>' => 25137,
+                    _ => 0,
+                };
+                break;
+            }
+
+            i += 1;
+            if i == line.len() - 1 {
+                incomplete.push(stack);
+                break;
+            }
+        }
+        total
+    });
+
+    (score, incomplete)
+}
+
+/// Close the incomplete lines from part one and return middle score
+pub fn part_two(incomplete: &[Vec<char>]) -> u64 {
+    let mut scores = incomplete.iter().fold(Vec::new(), |mut scores, line| {
+        scores.push(line.iter().rev().fold(0, |mut score: u64, sym| {
+            score *= 5;
+            match sym {
+                '(' => score += 1,
+                '[' => score += 2,
+                '{' => score += 3,
+                '<' => score += 4,
+                _ => (),
+            };
+            score
+        }));
+        scores
+    });
+    scores.sort_unstable();
+    scores[scores.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(super::part_one(&parse_input(EXAMPLE)).0, 26397);
+    }
+
+    #[test]
+    fn part_two() {
+        let (_, incomplete) = super::part_one(&parse_input(EXAMPLE));
+        assert_eq!(super::part_two(&incomplete), 288957);
+    }
+
+    #[test]
+    fn closing_every_chunk() {
+        let (score, incomplete) = super::part_one(&parse_input("()<>\n())"));
+        assert_eq!(score, 3);
+        assert!(incomplete[0].is_empty());
+    }
+}
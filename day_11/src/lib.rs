@@ -0,0 +1,197 @@
+use aoc_automaton::{Automaton, Boundary, Neighbourhood};
+use aoc_common::{budget, Answers, Error, Params, Violation};
+
+/// Side of the octopus grid in the puzzle
+pub const SIZE: usize = 10;
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Steps after which part one counts the flashes
+    pub part_one_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_steps: 100,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["part_one_steps"])?;
+        let default = Config::default();
+        Ok(Config {
+            part_one_steps: params.get("part_one_steps", default.part_one_steps, 0..)?,
+        })
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    let (part_one, part_two) = game_of_octopus(parse_input(input), config)?;
+
+    Ok((part_one.into(), Some(part_two.into())))
+}
+
+/// Checks that the grid is `SIZE` rows of `SIZE` energy levels.
+/// The solver handles other sizes, but those may never synchronise.
+pub fn check(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if let Some(column) = line.find(|c: char| !c.is_ascii_digit()) {
+            violations.push(Violation::token(
+                i,
+                line,
+                &line[column..],
+                "expected only digits",
+            ));
+        } else if line.len() != SIZE {
+            violations.push(Violation::line(
+                i,
+                format!("expected {} octopuses, found {}", SIZE, line.len()),
+            ));
+        }
+    }
+    let rows = input.lines().count();
+    if rows != SIZE {
+        violations.push(Violation::line(
+            rows.saturating_sub(1),
+            format!("expected {} rows, found {}", SIZE, rows),
+        ));
+    }
+    violations
+}
+
+/// The energy level of every octopus
+pub fn parse_input(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+/// An octopus' energy level and whether it flashed during the current step
+#[derive(Clone, Copy, PartialEq)]
+struct Octopus {
+    energy: u32,
+    flashed: bool,
+}
+
+impl Octopus {
+    fn flashes(&self) -> bool {
+        self.energy > 9 && !self.flashed
+    }
+}
+
+/// Flashes every charged octopus once, raising the energy of its neighbours.
+/// Stepping this until it settles plays out the chain of flashes.
+fn flash(neighbourhood: &Neighbourhood<Octopus>) -> Octopus {
+    let octopus = neighbourhood.centre();
+    let flashing = neighbourhood.neighbours().filter(|o| o.flashes()).count();
+    Octopus {
+        energy: octopus.energy + flashing as u32,
+        flashed: octopus.flashed || octopus.flashes(),
+    }
+}
+
+/// Returns the score of both parts, the part one score is the total flash count
+/// after `part_one_steps` turns. The part two score is the turn at which all octopi flash.
+pub fn game_of_octopus(energy: Vec<Vec<u32>>, config: &Config) -> Result<(usize, usize), Error> {
+    let octopi = energy
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|energy| Octopus {
+                    energy,
+                    flashed: false,
+                })
+                .collect()
+        })
+        .collect();
+    let mut grid = Automaton::new(octopi, Boundary::Finite);
+    let octopus_count = grid.width() * grid.height();
+
+    let mut flashes = 0;
+    let mut part_one_score = 0;
+    let mut synchronised = None;
+
+    let mut i = 0;
+    while i < config.part_one_steps || synchronised.is_none() {
+        budget::tick("game_of_octopus")?;
+        grid.update(|octopus| octopus.energy += 1);
+        grid.settle(&flash);
+
+        let flashed = grid.cells().filter(|o| o.flashed).count();
+        flashes += flashed;
+        grid.update(|octopus| {
+            if octopus.flashed {
+                *octopus = Octopus {
+                    energy: 0,
+                    flashed: false,
+                };
+            }
+        });
+
+        i += 1;
+        if i == config.part_one_steps {
+            part_one_score = flashes;
+        }
+        if flashed == octopus_count {
+            synchronised.get_or_insert(i);
+        }
+    }
+    Ok((part_one_score, synchronised.unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    #[test]
+    fn both_parts() {
+        assert_eq!(
+            game_of_octopus(parse_input(EXAMPLE), &Config::default()),
+            Ok((1656, 195))
+        );
+    }
+
+    #[test]
+    fn counts_flashes_after_synchronising() {
+        let config = Config { part_one_steps: 10 };
+        assert_eq!(
+            game_of_octopus(parse_input(EXAMPLE), &config),
+            Ok((204, 195))
+        );
+        // Every octopus is back to 0 after step 195, so none flash for the next 9 steps
+        let flashes = |part_one_steps| {
+            game_of_octopus(parse_input(EXAMPLE), &Config { part_one_steps }).unwrap()
+        };
+        assert_eq!(flashes(204), flashes(195));
+        assert_eq!(flashes(205).0, flashes(195).0 + 100);
+    }
+
+    #[test]
+    fn check_example() {
+        assert_eq!(check(EXAMPLE), []);
+    }
+}
@@ -0,0 +1,157 @@
+use aoc_common::{Answers, Error, Violation};
+use std::collections::HashMap;
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let adjacency_list = build_adjacency_list(parse_input(input));
+
+    Ok((
+        find_paths(&adjacency_list, false).into(),
+        Some(find_paths(&adjacency_list, true).into()),
+    ))
+}
+
+/// Checks that every line connects two caves, that there is a start and an end,
+/// and that no two big caves are connected, which would make the paths endless
+pub fn check(input: &str) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut caves = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let Some((a, b)) = line.split_once('-') else {
+            violations.push(Violation::line(i, "expected `cave-cave`"));
+            continue;
+        };
+        for cave in [a, b] {
+            if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                violations.push(Violation::token(
+                    i,
+                    line,
+                    cave,
+                    format!("{:?} is not a cave name", cave),
+                ));
+            }
+        }
+        let big = |cave: &str| !cave.is_empty() && cave.chars().all(|c| c.is_ascii_uppercase());
+        if big(a) && big(b) {
+            violations.push(Violation::line(
+                i,
+                format!("big caves {} and {} are connected", a, b),
+            ));
+        }
+        caves.extend([a, b]);
+    }
+
+    let last = input.lines().count().saturating_sub(1);
+    for required in ["start", "end"] {
+        if !caves.contains(&required) {
+            violations.push(Violation::line(
+                last,
+                format!("there is no {} cave", required),
+            ));
+        }
+    }
+    violations
+}
+
+pub fn parse_input(input: &str) -> Vec<(&str, &str)> {
+    input.lines().fold(Vec::new(), |mut edges, line| {
+        edges.push(line.split_once('-').unwrap());
+        edges
+    })
+}
+
+pub fn build_adjacency_list<'a>(edges: Vec<(&'a str, &'a str)>) -> HashMap<&'a str, Vec<&'a str>> {
+    edges.iter().fold(HashMap::new(), |mut adj_list, (a, b)| {
+        adj_list.entry(a).or_insert_with(Vec::new).push(b);
+        adj_list.entry(b).or_insert_with(Vec::new).push(a);
+        adj_list
+    })
+}
+
+fn is_lower(s: u8) -> bool {
+    ((s >> 5) & 1) != 0
+}
+
+/// A partial path through the caves
+#[derive(Clone)]
+struct Route<'a> {
+    cave: &'a str,
+    visited_small: Vec<&'a str>,
+    may_revisit: bool,
+}
+
+/// Counts the paths from start to end visiting small caves at most once,
+/// except for a single small cave visited twice if `may_revisit`
+pub fn find_paths(graph: &HashMap<&str, Vec<&str>>, may_revisit: bool) -> usize {
+    let start = Route {
+        cave: "start",
+        visited_small: Vec::new(),
+        may_revisit,
+    };
+
+    aoc_search::count_paths(
+        start,
+        |route| {
+            graph[route.cave]
+                .iter()
+                .filter(|&&cave| cave != "start")
+                .filter_map(|&cave| {
+                    let small = is_lower(cave.as_bytes()[0]);
+                    let revisit = small && route.visited_small.contains(&cave);
+                    if revisit && !route.may_revisit {
+                        return None;
+                    }
+                    let mut next = Route {
+                        cave,
+                        ..route.clone()
+                    };
+                    if revisit {
+                        next.may_revisit = false;
+                    } else if small {
+                        next.visited_small.push(cave);
+                    }
+                    Some(next)
+                })
+                .collect::<Vec<_>>()
+        },
+        |route| route.cave == "end",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one() {
+        let input = "\
+dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+        let adj_list = build_adjacency_list(parse_input(input));
+        assert_eq!(find_paths(&adj_list, false), 19);
+    }
+
+    #[test]
+    fn part_two() {
+        let input = "\
+start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+        let adj_list = build_adjacency_list(parse_input(input));
+        assert_eq!(find_paths(&adj_list, true), 36);
+    }
+}
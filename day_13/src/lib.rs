@@ -0,0 +1,178 @@
+use aoc_common::{Answers, Error};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let part_one = build_board(calculate_folds(parse_input(input), Some(1)))
+        .iter()
+        .flatten()
+        .filter(|&p| *p == '#')
+        .count();
+
+    let part_two = build_board(calculate_folds(parse_input(input), None));
+
+    Ok((part_one.into(), Some(part_two.into())))
+}
+
+type DotCoordinate = (i32, i32);
+type BoardDimensions = (usize, usize);
+
+#[derive(Clone, Copy, Debug)]
+pub enum Fold {
+    Left(usize),
+    Up(usize),
+}
+
+pub fn parse_input(input: &str) -> (Vec<DotCoordinate>, Vec<Fold>) {
+    let (dots, folds) = input.split_once("\n\n").unwrap();
+    (
+        dots.lines()
+            .map(|line| {
+                let (x, y) = line.split_once(',').unwrap();
+                (x.parse().unwrap(), y.parse().unwrap())
+            })
+            .collect(),
+        folds
+            .trim()
+            .lines()
+            .map(|line| {
+                let (char, num) = line[11..line.len()].split_once('=').unwrap();
+
+                if char == "x" {
+                    Fold::Left(num.parse().unwrap())
+                } else {
+                    Fold::Up(num.parse().unwrap())
+                }
+            })
+            .collect(),
+    )
+}
+
+/// The paper folds in half along the first fold lines, so it extends as far past them as before
+fn initial_size(folds: &[Fold]) -> BoardDimensions {
+    folds.iter().fold((0, 0), |sizes, fold| match fold {
+        Fold::Left(x) => (x * 2 + 1, sizes.1),
+        Fold::Up(y) => (sizes.0, y * 2 + 1),
+    })
+}
+
+pub fn calculate_folds(
+    input: (Vec<DotCoordinate>, Vec<Fold>),
+    limit: Option<usize>,
+) -> (Vec<DotCoordinate>, BoardDimensions) {
+    let (mut dots, folds) = input;
+    let (mut width, mut height) = initial_size(&folds[0..2]);
+
+    for fold in &folds[..limit.unwrap_or(folds.len())] {
+        match *fold {
+            Fold::Left(fold_line) => width = fold_line,
+            Fold::Up(fold_line) => height = fold_line,
+        }
+        fold_dots(&mut dots, fold);
+    }
+
+    (dots, (width, height))
+}
+
+/// Mirrors the dots past the fold line onto the other half
+fn fold_dots(dots: &mut [DotCoordinate], fold: &Fold) {
+    for (x, y) in dots {
+        let (coordinate, fold_line) = match *fold {
+            Fold::Left(fold_line) => (x, fold_line as i32),
+            Fold::Up(fold_line) => (y, fold_line as i32),
+        };
+        if *coordinate > fold_line {
+            *coordinate = (*coordinate - fold_line * 2).abs();
+        }
+    }
+}
+
+pub fn build_board(
+    (dots, (width, height)): (Vec<DotCoordinate>, BoardDimensions),
+) -> Vec<Vec<char>> {
+    dots.into_iter()
+        .fold(vec![vec!['.'; width]; height], |mut board, (x, y)| {
+            board[y as usize][x as usize] = '#';
+            board
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn part_one() {
+        let board = build_board(calculate_folds(parse_input(EXAMPLE), Some(1)));
+        assert_eq!(board.iter().flatten().filter(|&&p| p == '#').count(), 17);
+    }
+
+    #[test]
+    fn part_two() {
+        let board = build_board(calculate_folds(parse_input(EXAMPLE), None));
+        let rows: Vec<String> = board.iter().map(|row| row.iter().collect()).collect();
+        assert_eq!(
+            rows,
+            ["#####", "#...#", "#...#", "#...#", "#####", ".....", "....."]
+        );
+    }
+
+    #[test]
+    fn size_before_folding() {
+        assert_eq!(initial_size(&[Fold::Up(7), Fold::Left(5)]), (11, 15));
+        assert_eq!(initial_size(&[Fold::Left(655), Fold::Up(447)]), (1311, 895));
+    }
+
+    /// Dots on either side of a fold line, but not on it
+    fn folded_paper() -> impl Strategy<Value = (Vec<DotCoordinate>, Fold)> {
+        let fold = prop_oneof![
+            (1..50usize).prop_map(Fold::Left),
+            (1..50usize).prop_map(Fold::Up)
+        ];
+        fold.prop_flat_map(|fold| {
+            let line = match fold {
+                Fold::Left(line) | Fold::Up(line) => line as i32,
+            };
+            let across =
+                (0..=line * 2).prop_filter("dots are never on the fold line", move |&c| c != line);
+            let dot = match fold {
+                Fold::Left(_) => (across, 0..100i32).boxed(),
+                Fold::Up(_) => (0..100i32, across).boxed(),
+            };
+            (prop::collection::vec(dot, 1..50), Just(fold))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn folding_twice_changes_nothing((mut dots, fold) in folded_paper()) {
+            fold_dots(&mut dots, &fold);
+            let once = dots.clone();
+            fold_dots(&mut dots, &fold);
+            prop_assert_eq!(dots, once);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use aoc_common::{Answers, Error, Params};
+use std::collections::HashMap;
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Insertion steps before scoring part one
+    pub part_one_steps: u16,
+    /// Insertion steps before scoring part two
+    pub part_two_steps: u16,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_steps: 10,
+            part_two_steps: 40,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["part_one_steps", "part_two_steps"])?;
+        let default = Config::default();
+        Ok(Config {
+            part_one_steps: params.get("part_one_steps", default.part_one_steps, 0..)?,
+            part_two_steps: params.get("part_two_steps", default.part_two_steps, 0..)?,
+        })
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    let (part_one, part_two) = extend_polymer(parse_input(input), config)?;
+
+    Ok((part_one.into(), Some(part_two.into())))
+}
+
+pub fn parse_input(input: &str) -> (Vec<char>, HashMap<String, char>) {
+    let (template, insertion_rules) = input.split_once("\n\n").unwrap();
+
+    (
+        template.chars().collect(),
+        insertion_rules
+            .trim()
+            .lines()
+            .fold(HashMap::new(), |mut rules, line| {
+                let (pair, insertion) = line.split_once(" -> ").unwrap();
+                rules.insert(pair.to_string(), insertion.chars().next().unwrap());
+                rules
+            }),
+    )
+}
+
+fn count_initial_pairs(template: Vec<char>) -> HashMap<String, u64> {
+    let mut initial_pairs: HashMap<String, u64> = HashMap::new();
+    for i in 0..template.len() - 1 {
+        let counter = initial_pairs
+            .entry(format!("{}{}", template[i], template[i + 1]))
+            .or_insert(0);
+        *counter += 1;
+    }
+    initial_pairs
+}
+
+/// Inserts an element into every pair, splitting it into two pairs.
+/// None if a pair occurs too often to count in a `u64`.
+fn insert(
+    pairs: &HashMap<String, u64>,
+    rules: &HashMap<String, char>,
+) -> Option<HashMap<String, u64>> {
+    let mut updated_pairs: HashMap<String, u64> = HashMap::new();
+    pairs
+        .keys()
+        .map(|key| pairs.get_key_value(key).unwrap())
+        .try_for_each(|(k, v)| {
+            let left_pair = updated_pairs
+                .entry(format!(
+                    "{}{}",
+                    k.chars().next().unwrap(),
+                    *rules.get(k).unwrap()
+                ))
+                .or_insert(0);
+            *left_pair = left_pair.checked_add(*v)?;
+
+            let right_pair = updated_pairs
+                .entry(format!(
+                    "{}{}",
+                    *rules.get(k).unwrap(),
+                    k.chars().nth(1).unwrap()
+                ))
+                .or_insert(0);
+            *right_pair = right_pair.checked_add(*v)?;
+            Some(())
+        })?;
+
+    Some(updated_pairs)
+}
+
+/// Every element starts one pair, except the last one of the polymer, which never changes
+fn count_elements(pairs: &HashMap<String, u64>, last: char) -> Option<u64> {
+    let mut counts = HashMap::from([(last, 1u64)]);
+    pairs.iter().try_for_each(|(k, v)| {
+        let counter = counts.entry(k.chars().next().unwrap()).or_insert(0u64);
+        *counter = counter.checked_add(*v)?;
+        Some(())
+    })?;
+
+    Some(counts.values().max().unwrap() - counts.values().min().unwrap())
+}
+
+/// Returns the score of both parts after their number of insertion steps,
+/// failing once the polymer is too long to count in a `u64`
+pub fn extend_polymer(
+    (template, rules): (Vec<char>, HashMap<String, char>),
+    config: &Config,
+) -> Result<(u64, u64), Error> {
+    let overflow = |step| Error::Overflow(format!("the polymer outgrows a u64 at step {}", step));
+    let last = *template.last().unwrap();
+    let mut pairs: HashMap<String, u64> = count_initial_pairs(template);
+    let score = count_elements(&pairs, last).ok_or_else(|| overflow(0))?;
+    let mut scores = (score, score);
+
+    for step in 1..=config.part_one_steps.max(config.part_two_steps) {
+        pairs = insert(&pairs, &rules).ok_or_else(|| overflow(step))?;
+
+        if step == config.part_one_steps {
+            scores.0 = count_elements(&pairs, last).ok_or_else(|| overflow(step))?;
+        }
+        if step == config.part_two_steps {
+            scores.1 = count_elements(&pairs, last).ok_or_else(|| overflow(step))?;
+        }
+    }
+    Ok(scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn both_parts() {
+        assert_eq!(
+            extend_polymer(parse_input(EXAMPLE), &Config::default()),
+            Ok((1588, 2188189693529))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let config = Config {
+            part_one_steps: 10,
+            part_two_steps: 100,
+        };
+        assert_eq!(
+            extend_polymer(parse_input(EXAMPLE), &config)
+                .unwrap_err()
+                .to_string(),
+            "overflow: the polymer outgrows a u64 at step 65"
+        );
+    }
+
+    #[test]
+    fn fewer_steps() {
+        let config = Config {
+            part_one_steps: 0,
+            part_two_steps: 1,
+        };
+        // NNCB has two N, then NCNBCHB has two of everything but H
+        assert_eq!(extend_polymer(parse_input(EXAMPLE), &config), Ok((1, 1)));
+    }
+
+    /// A template over a few elements, with a rule for every pair of them
+    fn polymer() -> impl Strategy<Value = (Vec<char>, HashMap<String, char>)> {
+        let element = prop::sample::select(vec!['B', 'C', 'H', 'N']);
+        let template = prop::collection::vec(element.clone(), 2..20);
+        let rules = prop::collection::vec(element, 16).prop_map(|insertions| {
+            let elements = ['B', 'C', 'H', 'N'];
+            let pairs = elements
+                .iter()
+                .flat_map(|a| elements.iter().map(move |b| format!("{}{}", a, b)));
+            pairs.zip(insertions).collect()
+        });
+        (template, rules)
+    }
+
+    proptest! {
+        #[test]
+        fn pair_count_doubles((template, rules) in polymer(), steps in 1..20usize) {
+            let total = |pairs: &HashMap<String, u64>| pairs.values().sum::<u64>();
+            let mut pairs = count_initial_pairs(template.clone());
+            prop_assert_eq!(total(&pairs), template.len() as u64 - 1);
+            for _ in 0..steps {
+                let before = total(&pairs);
+                pairs = insert(&pairs, &rules).unwrap();
+                prop_assert_eq!(total(&pairs), before + before);
+            }
+        }
+    }
+}
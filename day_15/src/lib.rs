@@ -0,0 +1,300 @@
+use aoc_common::{Answers, Error, Params, Violation};
+use aoc_search::Graph;
+
+/// Side of the square cave in the puzzle input
+pub const GRID_SIZE: usize = 100;
+
+/// Rows plus columns of the largest cave whose risk totals fit in a `u16`,
+/// as the lowest risk is at most 9 for each step of a path along two sides
+pub const MAX_SIDES: usize = u16::MAX as usize / 9;
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Side of the square cave the input is checked against
+    pub grid_size: usize,
+    /// Times the cave is repeated in each direction for part two
+    pub expansion: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            grid_size: GRID_SIZE,
+            expansion: 5,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["grid_size", "expansion"])?;
+        let default = Config::default();
+        let grid_size = params.get("grid_size", default.grid_size, 1..=MAX_SIDES / 2)?;
+        Ok(Config {
+            grid_size,
+            expansion: params.get(
+                "expansion",
+                default.expansion,
+                1..=MAX_SIDES / 2 / grid_size,
+            )?,
+        })
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    let parsed_input = parse_input(input);
+    // Checked before expanding, so that an oversized cave fails without being built
+    let (rows, columns) = size(&parsed_input);
+    check_size(
+        rows.saturating_mul(config.expansion),
+        columns.saturating_mul(config.expansion),
+    )?;
+    let expanded = expand_matrix(&parsed_input, config.expansion);
+
+    Ok((
+        lowest_total_risk(&parsed_input)?.into(),
+        Some(lowest_total_risk(&expanded)?.into()),
+    ))
+}
+
+/// Checks that the cave is `GRID_SIZE` rows of `GRID_SIZE` risk levels from 1 to 9
+pub fn check(input: &str) -> Vec<Violation> {
+    check_with(input, &Config::default())
+}
+
+/// Like `check`, against the configured grid size
+pub fn check_with(input: &str, config: &Config) -> Vec<Violation> {
+    let grid_size = config.grid_size;
+    let mut violations = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if let Some(column) = line.find(|c| !('1'..='9').contains(&c)) {
+            violations.push(Violation::token(
+                i,
+                line,
+                &line[column..],
+                "expected only risk levels 1 to 9",
+            ));
+        } else if line.len() != grid_size {
+            violations.push(Violation::line(
+                i,
+                format!("expected {} columns, found {}", grid_size, line.len()),
+            ));
+        }
+    }
+    let rows = input.lines().count();
+    if rows != grid_size {
+        violations.push(Violation::line(
+            rows.saturating_sub(1),
+            format!("expected {} rows, found {}", grid_size, rows),
+        ));
+    }
+    violations
+}
+
+pub fn parse_input(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).unwrap() as u8)
+                .collect()
+        })
+        .collect()
+}
+
+/// The cave as a graph of positions, where entering a position costs its risk level
+struct RiskMap<'a>(&'a [Vec<u8>]);
+
+impl<'m> Graph for RiskMap<'m> {
+    type Node = (usize, usize);
+    type Cost = u16;
+
+    fn edges<'a>(
+        &'a self,
+        &(x, y): &(usize, usize),
+    ) -> impl IntoIterator<Item = ((usize, usize), u16)> + use<'a, 'm> {
+        aoc_search::orthogonal((x, y), self.0.len(), self.0[0].len())
+            .map(|(x, y)| ((x, y), self.0[x][y] as u16))
+    }
+}
+
+fn size(matrix: &[Vec<u8>]) -> (usize, usize) {
+    (matrix.len(), matrix.first().map_or(0, Vec::len))
+}
+
+/// Fails for an empty cave, or one whose risk totals could outgrow a `u16`
+fn check_size(rows: usize, columns: usize) -> Result<(), Error> {
+    if rows == 0 || columns == 0 {
+        let violation = Violation::line(0, "expected at least one risk level");
+        return Err(Error::InvalidInput(violation));
+    }
+    if rows.saturating_add(columns) > MAX_SIDES {
+        let message = format!("risk totals in a {}x{} cave", rows, columns);
+        return Err(Error::Overflow(message));
+    }
+    Ok(())
+}
+
+/// Returns the lowest total risk of any path from the top left to the bottom right
+pub fn lowest_total_risk(matrix: &[Vec<u8>]) -> Result<u16, Error> {
+    let (rows, columns) = size(matrix);
+    check_size(rows, columns)?;
+    let end = (rows - 1, columns - 1);
+
+    let index = |&(x, y): &(usize, usize)| x * columns + y;
+    RiskMap(matrix)
+        .dial((0, 0), rows * columns, index, 9, |&p| p == end)
+        .ok_or(Error::NoPath)
+}
+
+/// Raises every risk level by `i`, wrapping back around from 9 to 1
+fn increment_tile(tile: &[Vec<u8>], i: usize) -> Vec<Vec<u8>> {
+    let i = (i % 9) as u8;
+    tile.iter()
+        .map(|row| {
+            row.iter()
+                .map(|v| if *v + i > 9 { (v + i) - 9 } else { v + i })
+                .collect()
+        })
+        .collect()
+}
+
+fn expand_down(matrix: &[Vec<u8>], times: usize) -> Vec<Vec<u8>> {
+    let mut expanded = matrix.to_vec();
+    for i in 1..times {
+        expanded.extend(increment_tile(matrix, i));
+    }
+    expanded
+}
+
+fn expand_right(matrix: &[Vec<u8>], times: usize) -> Vec<Vec<u8>> {
+    let mut expanded = matrix.to_vec();
+    for i in 1..times {
+        let incremented = increment_tile(matrix, i);
+
+        for j in 0..expanded.len() {
+            expanded[j].extend(&incremented[j]);
+        }
+    }
+    expanded
+}
+
+/// Repeats the cave `times` times in each direction, each repetition one riskier
+pub fn expand_matrix(matrix: &[Vec<u8>], times: usize) -> Vec<Vec<u8>> {
+    expand_down(matrix, times)
+        .chunks(matrix.len())
+        .fold(Vec::new(), |mut expanded, tile| {
+            expanded.extend(expand_right(tile, times));
+            expanded
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(lowest_total_risk(&parse_input(EXAMPLE)), Ok(40));
+    }
+
+    #[test]
+    fn part_two() {
+        let expanded = expand_matrix(&parse_input(EXAMPLE), Config::default().expansion);
+        assert_eq!((expanded.len(), expanded[0].len()), (50, 50));
+        assert_eq!(lowest_total_risk(&expanded), Ok(315));
+    }
+
+    #[test]
+    fn configured_size() {
+        let config = Config {
+            grid_size: 10,
+            expansion: 1,
+        };
+        assert_eq!(check_with(EXAMPLE, &config), []);
+        assert_eq!(
+            solve_with(EXAMPLE, &config),
+            Ok((40u16.into(), Some(40u16.into())))
+        );
+    }
+
+    #[test]
+    fn risk_totals_fit() {
+        let mut params = Params::new();
+        params.set("expansion", 36);
+        assert_eq!(Config::from_params(&params).unwrap().expansion, 36);
+        params.set("expansion", 37);
+        assert_eq!(
+            Config::from_params(&params),
+            Err(Error::InvalidParam("expansion".to_string(), 37))
+        );
+
+        let row = |columns| vec![vec![9; columns]];
+        assert_eq!(
+            lowest_total_risk(&row(MAX_SIDES - 1)),
+            Ok(9 * (MAX_SIDES as u16 - 2))
+        );
+        assert_eq!(
+            lowest_total_risk(&row(MAX_SIDES)),
+            Err(Error::Overflow("risk totals in a 1x7281 cave".to_string()))
+        );
+    }
+
+    #[test]
+    fn checked_before_expanding() {
+        let config = Config {
+            grid_size: 10,
+            expansion: 365,
+        };
+        // Expanded, the example would take 133 million positions
+        assert_eq!(
+            solve_with(EXAMPLE, &config),
+            Err(Error::Overflow(
+                "risk totals in a 3650x3650 cave".to_string()
+            ))
+        );
+        assert!(matches!(
+            solve_with("", &Config::default()),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            lowest_total_risk(&[vec![]]),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    proptest! {
+        #[test]
+        fn risk_at_least_distance(
+            cave in (1..30usize, 1..30usize).prop_flat_map(|(width, height)| {
+                prop::collection::vec(prop::collection::vec(1..=9u8, width), height)
+            })
+        ) {
+            let distance = cave.len() - 1 + cave[0].len() - 1;
+            prop_assert!(lowest_total_risk(&cave)? as usize >= distance);
+            let end = (cave.len() - 1, cave[0].len() - 1);
+            let dijkstra = RiskMap(&cave).dijkstra((0, 0), |&p| p == end).map(|(_, risk)| risk);
+            prop_assert_eq!(lowest_total_risk(&cave).ok(), dijkstra);
+        }
+    }
+}
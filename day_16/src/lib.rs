@@ -0,0 +1,208 @@
+use aoc_common::bits::{BitReader, BitVec};
+use aoc_common::{Answers, Error};
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    let parsed = parse_input(input);
+
+    Ok((part_one(&parsed).into(), Some(part_two(&parsed).into())))
+}
+
+pub fn parse_input(input: &str) -> BitVec {
+    BitVec::from_hex(input.trim()).unwrap()
+}
+
+#[derive(Clone)]
+enum Packet {
+    O(Operator),
+    L(Literal),
+}
+
+#[derive(Clone)]
+struct Literal {
+    version: usize,
+    value: usize,
+}
+impl Literal {
+    fn new(version: usize, value: usize) -> Self {
+        Literal { version, value }
+    }
+}
+
+#[derive(Clone)]
+struct Operator {
+    version: usize,
+    type_id: usize,
+    sub_packets: Vec<Packet>,
+}
+impl Operator {
+    fn new(version: usize, type_id: usize, sub_packets: Vec<Packet>) -> Self {
+        Operator {
+            version,
+            type_id,
+            sub_packets,
+        }
+    }
+    fn sum_versions(&self) -> usize {
+        self.sub_packets.iter().fold(self.version, |mut sum, p| {
+            sum += match p {
+                Packet::L(literal) => literal.version,
+                Packet::O(operator) => operator.sum_versions(),
+            };
+            sum
+        })
+    }
+}
+
+fn decode_literal(bits: &mut BitReader) -> usize {
+    let mut value = 0;
+
+    while let Some(true) = bits.read_bit() {
+        value = value << 4 | bits.read(4).unwrap() as usize;
+    }
+    value << 4 | bits.read(4).unwrap() as usize
+}
+
+fn decode_operator(bits: &mut BitReader) -> Vec<Packet> {
+    let mut sub_packets = Vec::new();
+
+    match bits.read_bit().unwrap() {
+        false => {
+            let len_sub_bits = bits.read(15).unwrap() as usize;
+            let end = bits.position() + len_sub_bits;
+
+            while bits.position() < end {
+                sub_packets.push(decode(bits));
+            }
+        }
+        true => {
+            let num_sub_packets = bits.read(11).unwrap();
+
+            for _ in 0..num_sub_packets {
+                sub_packets.push(decode(bits));
+            }
+        }
+    }
+
+    sub_packets
+}
+
+fn decode(bits: &mut BitReader) -> Packet {
+    let version = bits.read(3).unwrap() as usize;
+    let type_id = bits.read(3).unwrap() as usize;
+
+    match type_id {
+        4 => Packet::L(Literal::new(version, decode_literal(bits))),
+        _ => Packet::O(Operator::new(version, type_id, decode_operator(bits))),
+    }
+}
+
+fn evaluate_packet(packet: &Packet) -> usize {
+    match packet {
+        Packet::L(literal) => literal.value,
+        Packet::O(Operator {
+            type_id,
+            sub_packets,
+            ..
+        }) => {
+            let sub_packets = sub_packets.iter().map(evaluate_packet);
+            match type_id {
+                0 => sub_packets.sum::<usize>(),
+                1 => sub_packets.fold(1, |mut val, p| {
+                    val *= p;
+                    val
+                }),
+                2 => sub_packets.min().unwrap(),
+                3 => sub_packets.max().unwrap(),
+                5 => {
+                    let values: Vec<usize> = sub_packets.collect();
+                    match values[0] > values[1] {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                6 => {
+                    let values: Vec<usize> = sub_packets.collect();
+                    match values[0] < values[1] {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                7 => {
+                    let values: Vec<usize> = sub_packets.collect();
+                    match values[0] == values[1] {
+                        true => 1,
+                        false => 0,
+                    }
+                }
+                _ => 0,
+            }
+        }
+    }
+}
+
+pub fn part_one(transmission: &BitVec) -> usize {
+    let decoded = decode(&mut transmission.reader());
+
+    match decoded {
+        Packet::L(literal) => literal.version,
+        Packet::O(operator) => operator.sum_versions(),
+    }
+}
+pub fn part_two(transmission: &BitVec) -> usize {
+    let decoded = decode(&mut transmission.reader());
+
+    evaluate_packet(&decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_input("D2FE28").to_string(),
+            "110100101111111000101000"
+        );
+    }
+    #[test]
+    fn part_one_0() {
+        let parsed = parse_input("8A004A801A8002F478");
+        assert_eq!(part_one(&parsed), 16);
+    }
+    #[test]
+    fn part_one_1() {
+        let parsed = parse_input("620080001611562C8802118E34");
+        assert_eq!(part_one(&parsed), 12);
+    }
+    #[test]
+    fn part_one_2() {
+        let parsed = parse_input("C0015000016115A2E0802F182340");
+        assert_eq!(part_one(&parsed), 23);
+    }
+    #[test]
+    fn part_one_3() {
+        let parsed = parse_input("A0016C880162017C3686B18A3D4780");
+        assert_eq!(part_one(&parsed), 31);
+    }
+    #[test]
+    fn part_two_0() {
+        let parsed = parse_input("C200B40A82");
+        assert_eq!(part_two(&parsed), 3);
+    }
+    #[test]
+    fn part_two_1() {
+        let parsed = parse_input("04005AC33890");
+        assert_eq!(part_two(&parsed), 54);
+    }
+    #[test]
+    fn part_two_2() {
+        let parsed = parse_input("880086C3E88112");
+        assert_eq!(part_two(&parsed), 7);
+    }
+    #[test]
+    fn part_two_3() {
+        let parsed = parse_input("9C0141080250320F1802104A08");
+        assert_eq!(part_two(&parsed), 1);
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_automaton::{Automaton, Boundary, Neighbourhood, Rule};
+use aoc_common::{bits, Answers, Error, Params};
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Times the image is enhanced for part one
+    pub part_one_steps: usize,
+    /// Times the image is enhanced for part two
+    pub part_two_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_steps: 2,
+            part_two_steps: 50,
+        }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["part_one_steps", "part_two_steps"])?;
+        let default = Config::default();
+        Ok(Config {
+            part_one_steps: params.get("part_one_steps", default.part_one_steps, 0..)?,
+            part_two_steps: params.get("part_two_steps", default.part_two_steps, 0..)?,
+        })
+    }
+}
+
+/// Solves both parts
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    Ok((
+        enhance(parse_input(input), config.part_one_steps).into(),
+        Some(enhance(parse_input(input), config.part_two_steps).into()),
+    ))
+}
+
+pub fn parse_input(input: &str) -> (Vec<bool>, Vec<Vec<bool>>) {
+    let (cipher, image) = input
+        .split_once("\n\n")
+        .or_else(|| input.split_once("\r\n\r\n"))
+        .unwrap();
+
+    (
+        cipher.chars().map(|c| c != '.').collect(),
+        image.trim().lines().fold(Vec::new(), |mut img, line| {
+            img.push(line.chars().map(|c| c != '.').collect());
+            img
+        }),
+    )
+}
+
+/// Looks up the new pixel in the cipher, indexed by the 3x3 square around it read as binary
+struct Enhance<'a>(&'a [bool]);
+
+impl Rule<bool> for Enhance<'_> {
+    fn next(&self, neighbourhood: &Neighbourhood<bool>) -> bool {
+        let index = bits::accumulate(neighbourhood.window().iter().map(|pixel| *pixel.unwrap()));
+        self.0[index as usize]
+    }
+}
+
+/// Returns the number of lit pixels after enhancing the image `steps` times.
+/// The image is surrounded by an infinite dark background, which the cipher
+/// may light up, so the count is only finite if it ends up dark again.
+pub fn enhance((cipher, image): (Vec<bool>, Vec<Vec<bool>>), steps: usize) -> usize {
+    let mut image = Automaton::new(image, Boundary::Infinite(false));
+    image.steps(&Enhance(&cipher), steps);
+
+    image.cells().filter(|&&pixel| pixel).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn part_one() {
+        assert_eq!(enhance(parse_input(EXAMPLE), 2), 35);
+    }
+
+    #[test]
+    fn part_two() {
+        assert_eq!(enhance(parse_input(EXAMPLE), 50), 3351);
+    }
+
+    #[test]
+    fn lit_background_goes_dark_again() {
+        // Cipher index 0 lights dark surroundings and index 511 darkens lit ones
+        let mut cipher = vec![false; 512];
+        cipher[0] = true;
+        let image = vec![vec![false]];
+        assert_eq!(enhance((cipher.clone(), image.clone()), 2), 0);
+        assert_eq!(enhance((cipher, image), 4), 0);
+    }
+}
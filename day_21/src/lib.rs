@@ -0,0 +1,122 @@
+use aoc_common::{Answers, Error, Params};
+
+/// Puzzle parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Config {
+    /// Score a player needs to win part one
+    pub target_score: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { target_score: 1000 }
+    }
+}
+
+impl Config {
+    pub fn from_params(params: &Params) -> Result<Self, Error> {
+        params.only(&["target_score"])?;
+        let default = Config::default();
+        Ok(Config {
+            target_score: params.get("target_score", default.target_score, 1..)?,
+        })
+    }
+}
+
+/// Solves both parts.
+/// Part two has not been solved yet.
+pub fn solve(input: &str) -> Result<Answers, Error> {
+    solve_with(input, &Config::default())
+}
+
+/// Solves both parts with the given parameters
+pub fn solve_with(input: &str, config: &Config) -> Result<Answers, Error> {
+    Ok((part_one(&parse_input(input), config).into(), None))
+}
+
+struct Player {
+    pos: u32,
+    score: u32,
+}
+impl Player {
+    fn forward(&mut self, moves: u32) {
+        self.pos = (self.pos + moves - 1) % 10 + 1;
+        self.score += self.pos;
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<u32> {
+    input.lines().fold(Vec::new(), |mut starts, line| {
+        starts.push(line.chars().last().unwrap().to_digit(10).unwrap());
+        starts
+    })
+}
+
+fn roll_dice(turn: u32) -> u32 {
+    (turn * 3) + (turn * 3 + 1) + (turn * 3 + 2) + 3
+}
+
+pub fn part_one(input: &[u32], config: &Config) -> u32 {
+    let mut p1 = Player {
+        pos: input[0],
+        score: 0,
+    };
+    let mut p2 = Player {
+        pos: input[1],
+        score: 0,
+    };
+
+    let mut turn = 0;
+    loop {
+        if p1.score >= config.target_score {
+            break p2.score * turn * 3;
+        }
+        if p2.score >= config.target_score {
+            break p1.score * turn * 3;
+        }
+
+        let moves = roll_dice(turn);
+        match turn % 2 {
+            0 => {
+                p1.forward(moves);
+            }
+            _ => {
+                p2.forward(moves);
+            }
+        }
+        turn += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn parse() {
+        assert_eq!(
+            parse_input(
+                "Player 1 starting position: 4
+                 Player 2 starting position: 8"
+            ),
+            [4, 8]
+        );
+    }
+    #[test]
+    fn part_one_test() {
+        let parsed = parse_input(
+            "Player 1 starting position: 4
+             Player 2 starting position: 8",
+        );
+        assert_eq!(part_one(&parsed, &Config::default()), 739785);
+    }
+    #[test]
+    fn lower_target() {
+        let parsed = parse_input(
+            "Player 1 starting position: 4
+             Player 2 starting position: 8",
+        );
+        // Player 1 reaches 10 on their first turn, after 3 rolls
+        let config = Config { target_score: 10 };
+        assert_eq!(part_one(&parsed, &config), 0);
+    }
+}